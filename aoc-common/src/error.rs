@@ -0,0 +1,231 @@
+use std::{fmt, io, path::PathBuf, process::ExitCode};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The binary was called with the wrong arguments
+    Usage(String),
+    /// The input file could not be read
+    Io { path: PathBuf, source: io::Error },
+    /// The parser could not make sense of the input at this position
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        expected: String,
+    },
+    /// The parser finished but left unparsed input behind
+    TrailingInput {
+        line: usize,
+        column: usize,
+        snippet: String,
+    },
+    /// The input is well formed but breaks an assumption of the puzzle
+    InvalidInput(String),
+    /// A request to the puzzle server failed
+    Fetch { url: String, message: String },
+}
+
+impl AocError {
+    /// A parse error pointing at the start of `rest`, which must be a
+    /// slice of `input`.
+    pub fn parse_at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let (line, column, snippet) = locate(input, rest);
+        AocError::Parse {
+            line,
+            column,
+            snippet,
+            expected: expected.into(),
+        }
+    }
+
+    /// A trailing input error pointing at the start of `rest`, which must be
+    /// a slice of `input`.
+    pub fn trailing_at(input: &str, rest: &str) -> Self {
+        let (line, column, snippet) = locate(input, rest);
+        AocError::TrailingInput {
+            line,
+            column,
+            snippet,
+        }
+    }
+
+    /// Converts a nom error into a located parse error. `input` is the text
+    /// that was handed to the top-level parser.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                AocError::parse_at(input, e.input, describe(e.code))
+            }
+            nom::Err::Incomplete(_) => {
+                AocError::parse_at(input, &input[input.len()..], "more input")
+            }
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            AocError::Usage(_) => ExitCode::from(2),
+            _ => ExitCode::FAILURE,
+        }
+    }
+
+    /// The offending input line with a caret under the error position, for
+    /// errors that have one.
+    pub fn diagnostic(&self) -> Option<String> {
+        let (line, column, snippet) = match self {
+            AocError::Parse {
+                line,
+                column,
+                snippet,
+                ..
+            }
+            | AocError::TrailingInput {
+                line,
+                column,
+                snippet,
+            } => (line, column, snippet),
+            _ => return None,
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        Some(format!(
+            "{} |\n{} | {}\n{} | {}^",
+            gutter,
+            line,
+            snippet,
+            gutter,
+            " ".repeat(column - 1)
+        ))
+    }
+}
+
+/// What a failed nom parser was looking for, in words for the diagnostic.
+fn describe(kind: nom::error::ErrorKind) -> String {
+    use nom::error::ErrorKind;
+    match kind {
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::Tag => "a fixed piece of text".to_string(),
+        ErrorKind::Char => "a specific character".to_string(),
+        ErrorKind::OneOf => "one of the allowed characters".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".to_string(),
+        ErrorKind::Eof => "the end of the input".to_string(),
+        ErrorKind::Verify => "a value that satisfies the puzzle rules".to_string(),
+        other => other.description().to_lowercase(),
+    }
+}
+
+/// The 1-based line and column of `rest` within `input`, along with the
+/// text of that line.
+fn locate(input: &str, rest: &str) -> (usize, usize, String) {
+    let offset = (rest.as_ptr() as usize)
+        .saturating_sub(input.as_ptr() as usize)
+        .min(input.len());
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i);
+    let line = before.matches('\n').count() + 1;
+    let column = input[line_start..offset].chars().count() + 1;
+    let snippet = input[line_start..line_end].trim_end_matches('\r');
+    (line, column, snippet.to_string())
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Usage(msg) => write!(f, "{}", msg),
+            AocError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            AocError::Parse {
+                line,
+                column,
+                expected,
+                ..
+            } => write!(
+                f,
+                "failed to parse input at line {}, column {}: expected {}",
+                line, column, expected
+            ),
+            AocError::TrailingInput { line, column, .. } => write!(
+                f,
+                "unexpected trailing input at line {}, column {}",
+                line, column
+            ),
+            AocError::InvalidInput(msg) => write!(f, "invalid input: {}", msg),
+            AocError::Fetch { url, message } => write!(f, "could not fetch {}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(
+            ExitCode::from(2),
+            AocError::Usage("usage".to_string()).exit_code()
+        );
+        assert_eq!(
+            ExitCode::FAILURE,
+            AocError::InvalidInput("bad".to_string()).exit_code()
+        );
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "12 34\n56 x8\n";
+        let err = AocError::parse_at(input, &input[9..], "a digit");
+        assert!(matches!(
+            &err,
+            AocError::Parse { line: 2, column: 4, snippet, expected }
+                if snippet == "56 x8" && expected == "a digit"
+        ));
+        assert_eq!(
+            "failed to parse input at line 2, column 4: expected a digit",
+            err.to_string()
+        );
+        assert_eq!(
+            Some("  |\n2 | 56 x8\n  |    ^".to_string()),
+            err.diagnostic()
+        );
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "abc";
+        let res: nom::IResult<&str, u64> = nom::character::complete::u64(input);
+        let err = AocError::from_nom(input, res.unwrap_err());
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_trailing_at_end_of_input() {
+        let input = "1\n2\n";
+        let err = AocError::trailing_at(input, &input[input.len()..]);
+        assert!(matches!(
+            &err,
+            AocError::TrailingInput { line: 3, column: 1, snippet } if snippet.is_empty()
+        ));
+        assert_eq!(None, AocError::InvalidInput("x".to_string()).diagnostic());
+    }
+}
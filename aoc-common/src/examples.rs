@@ -0,0 +1,177 @@
+//! Puzzle examples kept as data files instead of inline test strings.
+//!
+//! Each day keeps its examples in `data/examples/<name>.txt`, with the
+//! expected answers in a `<name>.answers` sidecar next to it:
+//!
+//! ```text
+//! # answers for sample.txt
+//! part1 = 143
+//! part2 = 123
+//! ```
+//!
+//! A day opts in with a build script that calls [`generate_tests`] and a
+//! call to [`example_tests!`](crate::example_tests) in its `lib.rs`. That
+//! produces one test per example per part, so adding an example is just a
+//! matter of dropping two files into `data/examples`.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{solve_str, AocError, Solution};
+
+/// The expected answers from a sidecar file, as `(part, answer)` pairs.
+pub fn parse_answers(text: &str) -> Result<Vec<(u8, String)>, AocError> {
+    let mut answers = vec![];
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let part = trimmed
+            .split_once('=')
+            .and_then(|(key, value)| Some((key.trim().strip_prefix("part")?, value)))
+            .and_then(|(part, value)| Some((part.parse().ok()?, value)));
+        match part {
+            Some((part, value)) => {
+                let answer = value.trim().trim_matches('"').to_string();
+                answers.push((part, answer));
+            }
+            None => return Err(AocError::parse_at(text, line, "partN = answer")),
+        }
+    }
+    Ok(answers)
+}
+
+pub fn load_answers(path: &Path) -> Result<Vec<(u8, String)>, AocError> {
+    let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_answers(&text)
+}
+
+/// The sidecar that holds the answers for an example input.
+pub fn answers_path(example: &Path) -> PathBuf {
+    example.with_extension("answers")
+}
+
+/// Solves the example at `path` with `S` and panics unless the answer is
+/// `expected`. Called from the generated tests.
+pub fn check<S: Solution>(path: &str, expected: &str) {
+    let input = fs::read_to_string(path).unwrap_or_else(|err| panic!("{}: {}", path, err));
+    match solve_str::<S>(&input) {
+        Ok(answer) => assert_eq!(expected, answer.to_string(), "wrong answer for {}", path),
+        Err(err) => panic!("{}: {}", path, err),
+    }
+}
+
+/// Writes `$OUT_DIR/examples.rs` with a test for every answer of every
+/// example in `data/examples`. Meant to be called from a day's `build.rs`.
+///
+/// # Panics
+///
+/// If an example has no sidecar, a sidecar is malformed, or it has an answer
+/// for a part that the day does not have, so that the build fails loudly.
+pub fn generate_tests() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("run from build.rs"));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("run from build.rs"));
+    let examples_dir = manifest_dir.join("data/examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut examples: Vec<PathBuf> = fs::read_dir(&examples_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+    examples.sort();
+
+    let mut tests = String::new();
+    for example in examples {
+        let sidecar = answers_path(&example);
+        let answers = load_answers(&sidecar).unwrap_or_else(|err| panic!("{}", err));
+        let name = test_name(&example);
+        for (part, answer) in answers {
+            assert!(
+                manifest_dir.join(format!("src/part{}.rs", part)).exists(),
+                "{} has an answer for part {}, which does not exist",
+                sidecar.display(),
+                part
+            );
+            writeln!(
+                tests,
+                "#[test]\nfn {}_part{}() {{\n    aoc_common::examples::check::<crate::part{}::Part{}>({:?}, {:?});\n}}\n",
+                name,
+                part,
+                part,
+                part,
+                example.display().to_string(),
+                answer
+            )
+            .unwrap();
+        }
+    }
+    fs::write(out_dir.join("examples.rs"), tests).expect("could not write examples.rs");
+}
+
+/// Turns an example's file stem into a valid function name.
+fn test_name(example: &Path) -> String {
+    let stem = example
+        .file_stem()
+        .map(|s| s.to_string_lossy())
+        .unwrap_or_default();
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("example_{}", name.to_lowercase())
+}
+
+/// Pulls in the tests written by [`generate_tests`].
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# sample\npart1 = 143\n\npart2 = \"abc\"\n").unwrap();
+        assert_eq!(
+            vec![(1, "143".to_string()), (2, "abc".to_string())],
+            answers
+        );
+    }
+
+    #[test]
+    fn test_parse_answers_error() {
+        let err = parse_answers("part1 = 1\nanswer: 2\n").unwrap_err();
+        assert!(matches!(err, AocError::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            "example_larger_2",
+            test_name(Path::new("data/examples/larger-2.txt"))
+        );
+        assert_eq!(
+            Path::new("data/examples/larger-2.answers"),
+            answers_path(Path::new("data/examples/larger-2.txt"))
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{AocError, Direction, Point};
+
+/// A rectangular grid stored as a single row-major `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid<char> {
+    /// Parses one row per line, keeping every character as a cell.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    ///
+    /// If `cells` does not hold exactly `rows * cols` elements.
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(rows * cols, cells.len(), "grid size does not match cells");
+        Grid { cells, rows, cols }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(rows, cols, vec![value; rows * cols])
+    }
+
+    /// # Panics
+    ///
+    /// If the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, |r| r.len());
+        let mut cells = Vec::with_capacity(num_rows * num_cols);
+        for row in rows {
+            assert_eq!(num_cols, row.len(), "grid rows have different lengths");
+            cells.extend(row);
+        }
+        Grid::new(num_rows, num_cols, cells)
+    }
+
+    /// Parses one row per line, converting each character with `f`.
+    ///
+    /// Fails at the first line whose length differs from the first one.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        Self::try_parse_with(input, "", |c| Some(f(c)))
+    }
+
+    /// Like [`Grid::parse_with`], but `f` can reject a character, which
+    /// fails with `expected` at that character.
+    pub fn try_parse_with(
+        input: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, AocError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for line in input.lines() {
+            let mut row = Vec::with_capacity(line.len());
+            for (i, c) in line.char_indices() {
+                match f(c) {
+                    Some(cell) => row.push(cell),
+                    None => return Err(AocError::parse_at(input, &line[i..], expected)),
+                }
+            }
+            if let Some(first) = rows.first() {
+                if first.len() != row.len() {
+                    let expected = format!("a row of {} cells", first.len());
+                    return Err(AocError::parse_at(input, line, expected));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.row >= 0 && p.col >= 0 && (p.row as usize) < self.rows && (p.col as usize) < self.cols
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.in_bounds(p)
+            .then(|| p.row as usize * self.cols + p.col as usize)
+    }
+
+    fn point_at(&self, offset: usize) -> Point {
+        Point::new((offset / self.cols) as isize, (offset % self.cols) as isize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_at(i))
+    }
+
+    /// Every cell alongside its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.point_at(i), c))
+    }
+
+    /// The in-bounds neighbours of `p` in the order of [`Direction::CARDINAL`].
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Direction::CARDINAL)
+    }
+
+    /// The in-bounds neighbours of `p` in the order of [`Direction::ALL`].
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        p: Point,
+        dirs: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        dirs.iter()
+            .map(move |d| p + *d)
+            .filter(|n| self.in_bounds(*n))
+    }
+
+    /// The first position, in row-major order, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|c| c == value)
+            .map(|i| self.point_at(i))
+    }
+
+    /// Every position holding `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1))
+    }
+
+    /// Walks from `start` in steps of `step` until leaving the grid.
+    /// `start` itself is the first item when it is in bounds.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |p| Some(*p + step))
+            .map_while(|p| self.get(p).map(|c| (p, c)))
+    }
+
+    /// The down-right diagonal starting at `start`.
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(start, Direction::SE.offset())
+    }
+
+    /// The down-left diagonal starting at `start`.
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(start, Direction::SW.offset())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// If `p` is out of bounds. Use [`Grid::get`] for a checked lookup.
+    fn index(&self, p: Point) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{} is outside a {}x{} grid", p, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{} is outside a {}x{} grid", p, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!(2, grid.rows());
+        assert_eq!(3, grid.cols());
+        assert_eq!('a', grid[Point::new(0, 0)]);
+        assert_eq!('f', grid[Point::new(1, 2)]);
+        assert_eq!("abc\ndef", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let err = Grid::parse("ab\ncde").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_try_parse_with() {
+        let digit = |c: char| c.to_digit(10);
+        let grid = Grid::try_parse_with("12\n34", "a digit", digit).unwrap();
+        assert_eq!(4, grid[Point::new(1, 1)]);
+        let err = Grid::try_parse_with("12\n3x", "a digit", digit).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 2,
+                ref expected,
+                ..
+            } if expected == "a digit"
+        ));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = sample();
+        assert_eq!(Some(&'e'), grid.get(Point::new(1, 1)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(None, grid.get(Point::new(0, 3)));
+        assert_eq!(None, grid.get(Point::new(2, 0)));
+        *grid.get_mut(Point::new(0, 1)).unwrap() = 'x';
+        grid[Point::new(1, 0)] = 'y';
+        assert_eq!("axc\nyef", grid.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let _ = sample()[Point::new(0, -1)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let n4: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(vec![Point::new(0, 1), Point::new(1, 0)], n4);
+        let n8: Vec<_> = grid.neighbours8(Point::new(1, 1)).collect();
+        assert_eq!(
+            vec![
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(1, 2),
+                Point::new(1, 0),
+                Point::new(0, 0)
+            ],
+            n8
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("a.a\n.a.").unwrap();
+        assert_eq!(Some(Point::new(0, 0)), grid.find(&'a'));
+        assert_eq!(None, grid.find(&'z'));
+        let all: Vec<_> = grid.find_all(&'a').collect();
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(0, 2), Point::new(1, 1)],
+            all
+        );
+    }
+
+    #[test]
+    fn test_rows_columns_diagonals() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(3, grid.iter_rows().count());
+        assert_eq!("beh", grid.column(1).collect::<String>());
+        let diag: String = grid.diagonal(Point::new(0, 0)).map(|(_, c)| c).collect();
+        assert_eq!("aei", diag);
+        let anti: String = grid
+            .anti_diagonal(Point::new(0, 2))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!("ceg", anti);
+        let ray: String = grid
+            .ray(Point::new(2, 2), Point::new(0, -1))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!("ihg", ray);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        let doubled = grid.map(|x| x * 2);
+        assert_eq!("24\n68", doubled.to_string());
+    }
+}
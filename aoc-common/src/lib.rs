@@ -0,0 +1,34 @@
+mod error;
+pub mod examples;
+pub mod gen;
+pub mod grid;
+pub mod par;
+mod parse;
+mod point;
+mod registry;
+mod report;
+mod runner;
+pub mod viz;
+
+use std::fmt::Display;
+
+pub use error::AocError;
+pub use grid::Grid;
+pub use parse::parse_complete;
+pub use point::{Direction, Point};
+pub use registry::{Solver, Timed};
+pub use report::{Format, Report};
+pub use runner::{run, solve_str};
+
+/// A single puzzle part, split into a parse step and a solve step.
+///
+/// Each day implements this once per part and hands the type to [`run`],
+/// which takes care of reading the input file and reporting the result.
+pub trait Solution {
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn solve(input: &Self::Input) -> Self::Output;
+}
@@ -0,0 +1,151 @@
+//! Loops over independent work items that can run on the rayon thread pool.
+//!
+//! Solvers call these helpers with [`Mode::current`], which is
+//! [`Mode::DEFAULT`] until [`set_mode`] picks the other one: [`Mode::Parallel`]
+//! when the crate is built with the `parallel` feature and
+//! [`Mode::Sequential`] otherwise. Without the feature both modes run
+//! sequentially, so tests can always compare the two.
+//!
+//! Every helper reduces in a way that does not depend on how the items were
+//! split between threads, so the answer is the same in either mode.
+
+use std::{
+    iter::Sum,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Sequential,
+    Parallel,
+}
+
+impl Mode {
+    pub const DEFAULT: Mode = if cfg!(feature = "parallel") {
+        Mode::Parallel
+    } else {
+        Mode::Sequential
+    };
+
+    /// The mode solvers run in right now.
+    pub fn current() -> Mode {
+        if PARALLEL.load(Ordering::Relaxed) {
+            Mode::Parallel
+        } else {
+            Mode::Sequential
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn is_parallel(self) -> bool {
+        self == Mode::Parallel
+    }
+}
+
+static PARALLEL: AtomicBool = AtomicBool::new(matches!(Mode::DEFAULT, Mode::Parallel));
+
+/// Runs every solver in `mode` from now on, so whole solvers can be compared
+/// in both modes.
+pub fn set_mode(mode: Mode) {
+    PARALLEL.store(mode == Mode::Parallel, Ordering::Relaxed);
+}
+
+/// Sums `f` over `items`.
+pub fn sum_by<T, R, F>(mode: Mode, items: &[T], f: F) -> R
+where
+    T: Sync,
+    R: Send + Sum,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if mode.is_parallel() {
+        return items.par_iter().map(f).sum();
+    }
+    let _ = mode;
+    items.iter().map(f).sum()
+}
+
+/// Counts the items that satisfy `pred`.
+pub fn count_where<T, F>(mode: Mode, items: &[T], pred: F) -> usize
+where
+    T: Sync,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    sum_by(mode, items, |item| usize::from(pred(item)))
+}
+
+/// The item with the smallest key, taking the earliest one on ties.
+pub fn min_by_key<T, K, F>(mode: Mode, items: &[T], f: F) -> Option<&T>
+where
+    T: Sync,
+    K: Ord + Send,
+    F: Fn(&T) -> K + Sync + Send,
+{
+    // keying on the index as well makes ties independent of the split
+    #[cfg(feature = "parallel")]
+    if mode.is_parallel() {
+        return items
+            .par_iter()
+            .enumerate()
+            .min_by_key(|(i, item)| (f(item), *i))
+            .map(|(_, item)| item);
+    }
+    let _ = mode;
+    items
+        .iter()
+        .enumerate()
+        .min_by_key(|(i, item)| (f(item), *i))
+        .map(|(_, item)| item)
+}
+
+/// Sizes the global thread pool. Must be called before any parallel work.
+///
+/// Builds without the `parallel` feature only ever use one thread, so asking
+/// for more is a usage error there.
+pub fn set_threads(threads: usize) -> Result<(), AocError> {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| AocError::Usage(format!("could not start {} threads: {}", threads, err)))
+    }
+    #[cfg(not(feature = "parallel"))]
+    if threads > 1 {
+        Err(AocError::Usage(
+            "--threads needs a build with the parallel feature".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [Mode; 2] = [Mode::Sequential, Mode::Parallel];
+
+    #[test]
+    fn test_sum_by() {
+        let items: Vec<u64> = (1..=1000).collect();
+        for mode in MODES {
+            assert_eq!(500500, sum_by(mode, &items, |x| *x));
+            assert_eq!(500, count_where(mode, &items, |x| x % 2 == 0));
+        }
+    }
+
+    #[test]
+    fn test_min_by_key_takes_first_tie() {
+        let items: Vec<(u32, usize)> = (0..10_000).map(|i| ((i % 7) as u32, i)).collect();
+        for mode in MODES {
+            assert_eq!(Some(&(0, 0)), min_by_key(mode, &items, |x| x.0));
+        }
+        assert_eq!(None, min_by_key(Mode::Parallel, &[] as &[u32], |x| *x));
+    }
+}
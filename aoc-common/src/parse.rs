@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use nom::IResult;
+
+use crate::AocError;
+
+/// Runs `parser` over the whole of `input` and insists that it consumes all
+/// of it.
+///
+/// `\r\n` line endings are read as `\n` and a single trailing newline is
+/// allowed, so parsers only ever have to deal with `\n` between lines.
+/// Anything else left over is an [`AocError::TrailingInput`] rather than
+/// being silently dropped.
+pub fn parse_complete<T>(
+    input: &str,
+    mut parser: impl FnMut(&str) -> IResult<&str, T>,
+) -> Result<T, AocError> {
+    let input = normalize_line_endings(input);
+    let body = input.strip_suffix('\n').unwrap_or(&input);
+    match parser(body) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(AocError::trailing_at(body, rest)),
+        Err(err) => Err(AocError::from_nom(body, err)),
+    }
+}
+
+fn normalize_line_endings(input: &str) -> Cow<'_, str> {
+    if input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        character::complete::{newline, u32},
+        multi::separated_list1,
+    };
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(newline, u32)(input)
+    }
+
+    #[test]
+    fn test_parse_complete() {
+        assert_eq!(vec![1, 2, 3], parse_complete("1\n2\n3", numbers).unwrap());
+        assert_eq!(vec![1, 2, 3], parse_complete("1\n2\n3\n", numbers).unwrap());
+        assert_eq!(
+            vec![1, 2, 3],
+            parse_complete("1\r\n2\r\n3\r\n", numbers).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_complete_trailing() {
+        let err = parse_complete("1\n2\nx\n", numbers).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::TrailingInput {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_complete("1\n2\n\n", numbers),
+            Err(AocError::TrailingInput { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse_complete("1\n2\r3", numbers),
+            Err(AocError::TrailingInput { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_complete_error() {
+        let err = parse_complete("x", numbers).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position or offset on a grid, as `row` down and `col` across.
+///
+/// Puzzles that talk about `x, y` should go through [`Point::from_xy`]
+/// so that `x` always lands in `col` and `y` in `row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    pub const fn from_xy(x: isize, y: isize) -> Self {
+        Point { row: y, col: x }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, k: isize) -> Point {
+        Point::new(self.row * k, self.col * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction) -> Point {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl Sub<Direction> for Point {
+    type Output = Point;
+
+    fn sub(self, dir: Direction) -> Point {
+        self - dir.offset()
+    }
+}
+
+/// A compass direction, with north pointing up the grid (decreasing row).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// Up, right, down, left, in that order.
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All eight directions, clockwise starting from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::N => Point::new(-1, 0),
+            Direction::NE => Point::new(-1, 1),
+            Direction::E => Point::new(0, 1),
+            Direction::SE => Point::new(1, 1),
+            Direction::S => Point::new(1, 0),
+            Direction::SW => Point::new(1, -1),
+            Direction::W => Point::new(0, -1),
+            Direction::NW => Point::new(-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|d| *d == self).unwrap();
+        Direction::ALL[(index + eighths) % 8]
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::N | Direction::S)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::E | Direction::W)
+    }
+
+    /// Reads one of the `^>v<` arrows used for moves in the puzzle inputs.
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::N),
+            '>' => Some(Direction::E),
+            'v' => Some(Direction::S),
+            '<' => Some(Direction::W),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Point {
+        dir.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(3, -4);
+        assert_eq!(Point::new(4, -2), a + b);
+        assert_eq!(Point::new(-2, 6), a - b);
+        assert_eq!(Point::new(3, 6), a * 3);
+        assert_eq!(Point::new(-1, -2), -a);
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(4, a.chebyshev(b));
+        assert_eq!(0, a.manhattan(a));
+    }
+
+    #[test]
+    fn test_from_xy() {
+        let p = Point::from_xy(3, 7);
+        assert_eq!(7, p.row);
+        assert_eq!(3, p.col);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::E, Direction::N.turn_right());
+        assert_eq!(Direction::W, Direction::N.turn_left());
+        assert_eq!(Direction::SW, Direction::NE.opposite());
+        assert_eq!(Direction::N, Direction::W.turn_right());
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.offset(), -dir.opposite().offset());
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let p = Point::new(5, 5);
+        assert_eq!(Point::new(4, 5), p + Direction::N);
+        assert_eq!(Point::new(6, 4), p + Direction::SW);
+        assert_eq!(Point::new(5, 4), p - Direction::E);
+    }
+
+    #[test]
+    fn test_from_arrow() {
+        let dirs: Vec<_> = "^>v<".chars().filter_map(Direction::from_arrow).collect();
+        assert_eq!(Direction::CARDINAL.to_vec(), dirs);
+        assert_eq!(None, Direction::from_arrow('x'));
+    }
+}
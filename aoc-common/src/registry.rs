@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant};
+
+use crate::{solve_str, AocError, Solution};
+
+/// A type-erased handle on one day/part, so a single binary can dispatch
+/// to any of the registered solutions.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    run: fn(&str) -> Result<String, AocError>,
+    timed: fn(&str) -> Result<Timed, AocError>,
+}
+
+/// The answer from a single run along with how long each phase took.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(day: u8, part: u8) -> Self {
+        Solver {
+            day,
+            part,
+            run: run_to_string::<S>,
+            timed: run_timed::<S>,
+        }
+    }
+
+    /// Parses and solves `input`, returning the rendered answer.
+    pub fn run(&self, input: &str) -> Result<String, AocError> {
+        (self.run)(input)
+    }
+
+    /// Like [`Solver::run`], but times the parse and solve steps separately.
+    pub fn run_timed(&self, input: &str) -> Result<Timed, AocError> {
+        (self.timed)(input)
+    }
+
+    /// The conventional input location, relative to the workspace root.
+    pub fn default_input(&self) -> String {
+        format!("day-{:02}/data/day-{:02}.txt", self.day, self.day)
+    }
+
+    /// Where the example called `name` lives, relative to the workspace root.
+    pub fn example_input(&self, name: &str) -> String {
+        format!("day-{:02}/data/examples/{}.txt", self.day, name)
+    }
+}
+
+fn run_to_string<S: Solution>(input: &str) -> Result<String, AocError> {
+    solve_str::<S>(input).map(|answer| answer.to_string())
+}
+
+pub(crate) fn run_timed<S: Solution>(input: &str) -> Result<Timed, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let answer = S::solve(&parsed);
+    let solve = start.elapsed();
+    Ok(Timed {
+        answer: answer.to_string(),
+        parse,
+        solve,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type Output = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.lines().count())
+        }
+
+        fn solve(input: &Self::Input) -> Self::Output {
+            *input
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = Solver::new::<Count>(3, 1);
+        assert_eq!("2", solver.run("a\nb").unwrap());
+    }
+
+    #[test]
+    fn test_run_timed() {
+        let solver = Solver::new::<Count>(3, 1);
+        assert_eq!("3", solver.run_timed("a\nb\nc").unwrap().answer);
+    }
+
+    #[test]
+    fn test_default_input() {
+        let solver = Solver::new::<Count>(3, 1);
+        assert_eq!("day-03/data/day-03.txt", solver.default_input());
+        assert_eq!(
+            "day-03/data/examples/sample.txt",
+            solver.example_input("sample")
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::{path::Path, str::FromStr};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{AocError, Timed};
+
+/// How a finished run is printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Solution is ...`, for people
+    #[default]
+    Text,
+    /// One JSON object per line, for other tools
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(AocError::Usage(format!(
+                "unknown format {:?}, expected text or json",
+                other
+            ))),
+        }
+    }
+}
+
+/// The result of one run in the shape printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    /// A JSON number when the answer is an integer, otherwise a string
+    pub answer: Value,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+    pub input: String,
+}
+
+impl Report {
+    pub fn new(day: u8, part: u8, timed: &Timed, input: &Path) -> Self {
+        Report {
+            day,
+            part,
+            answer: typed_answer(&timed.answer),
+            parse_ms: timed.parse.as_secs_f64() * 1000.0,
+            solve_ms: timed.solve.as_secs_f64() * 1000.0,
+            input: input.display().to_string(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialise")
+    }
+
+    /// Prints the report as a single line in the given format.
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Text => println!("Solution is {}", self.answer_text()),
+            Format::Json => println!("{}", self.to_json()),
+        }
+    }
+
+    fn answer_text(&self) -> String {
+        match &self.answer {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Integer answers become JSON numbers so they can be compared as such.
+/// Anything else, including integers too large for 64 bits, stays a string.
+fn typed_answer(answer: &str) -> Value {
+    if let Ok(n) = answer.parse::<i64>() {
+        Value::from(n)
+    } else if let Ok(n) = answer.parse::<u64>() {
+        Value::from(n)
+    } else {
+        Value::from(answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn timed(answer: &str) -> Timed {
+        Timed {
+            answer: answer.to_string(),
+            parse: Duration::from_micros(1500),
+            solve: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(Format::Json, "json".parse().unwrap());
+        assert_eq!(Format::Text, "text".parse().unwrap());
+        assert!(matches!("yaml".parse::<Format>(), Err(AocError::Usage(_))));
+    }
+
+    #[test]
+    fn test_typed_answer() {
+        assert_eq!(Value::from(-12), typed_answer("-12"));
+        assert_eq!(Value::from(u64::MAX), typed_answer(&u64::MAX.to_string()));
+        assert_eq!(Value::from("6,0,2"), typed_answer("6,0,2"));
+        assert_eq!(
+            Value::from("99999999999999999999"),
+            typed_answer("99999999999999999999")
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = Report::new(3, 2, &timed("48"), Path::new("day-03/data/day-03.txt"));
+        assert_eq!(
+            r#"{"day":3,"part":2,"answer":48,"parse_ms":1.5,"solve_ms":2.0,"input":"day-03/data/day-03.txt"}"#,
+            report.to_json()
+        );
+        assert_eq!("48", report.answer_text());
+        let report = Report::new(1, 1, &timed("a\"b"), Path::new("x.txt"));
+        assert!(report.to_json().contains(r#""answer":"a\"b""#));
+        assert_eq!("a\"b", report.answer_text());
+    }
+}
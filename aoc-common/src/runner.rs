@@ -0,0 +1,199 @@
+use std::{env, fs::read_to_string, path::PathBuf, process::ExitCode};
+
+use crate::{par, registry, AocError, Format, Report, Solution};
+
+/// Entry point shared by every `partN` binary.
+///
+/// Reads the input file given as the only argument, parses and solves it,
+/// and prints the answer, or a JSON [`Report`] with `--format json`.
+/// `--threads N` sizes the thread pool as [`par::set_threads`] does. Errors
+/// go to stderr with a non-zero exit code.
+pub fn run<S: Solution>(day: u8, part: u8) -> ExitCode {
+    match try_run::<S>(day, part, env::args().skip(1)) {
+        Ok((report, format)) => {
+            report.print(format);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            if let Some(diagnostic) = err.diagnostic() {
+                eprintln!("{}", diagnostic);
+            }
+            err.exit_code()
+        }
+    }
+}
+
+fn try_run<S: Solution>(
+    day: u8,
+    part: u8,
+    args: impl Iterator<Item = String>,
+) -> Result<(Report, Format), AocError> {
+    let Args {
+        path: data_path,
+        format,
+        threads,
+    } = parse_args(args)?;
+    if let Some(threads) = threads {
+        par::set_threads(threads)?;
+    }
+    let data = read_to_string(&data_path).map_err(|source| AocError::Io {
+        path: data_path.clone(),
+        source,
+    })?;
+    let timed = registry::run_timed::<S>(&data)?;
+    Ok((Report::new(day, part, &timed, &data_path), format))
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    path: PathBuf,
+    format: Format,
+    threads: Option<usize>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, AocError> {
+    let usage =
+        || AocError::Usage("usage: [--format text|json] [--threads N] <input file>".to_string());
+    let threads = |value: &str| match value.parse() {
+        Ok(threads) if threads > 0 => Ok(threads),
+        _ => Err(usage()),
+    };
+    let mut path = None;
+    let mut format = Format::Text;
+    let mut thread_count = None;
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--format=") {
+            format = value.parse()?;
+        } else if arg == "--format" {
+            format = args.next().ok_or_else(usage)?.parse()?;
+        } else if let Some(value) = arg.strip_prefix("--threads=") {
+            thread_count = Some(threads(value)?);
+        } else if arg == "--threads" {
+            thread_count = Some(threads(&args.next().ok_or_else(usage)?)?);
+        } else if path.is_none() && !arg.starts_with("--") {
+            path = Some(PathBuf::from(arg));
+        } else {
+            return Err(usage());
+        }
+    }
+    Ok(Args {
+        path: path.ok_or_else(usage)?,
+        format,
+        threads: thread_count,
+    })
+}
+
+/// Parses and solves `input` in one go.
+pub fn solve_str<S: Solution>(input: &str) -> Result<S::Output, AocError> {
+    let parsed = S::parse(input)?;
+    Ok(S::solve(&parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            input
+                .split_whitespace()
+                .map(|x| {
+                    x.parse()
+                        .map_err(|_| AocError::parse_at(input, x, "a number"))
+                })
+                .collect()
+        }
+
+        fn solve(input: &Self::Input) -> Self::Output {
+            input.iter().sum()
+        }
+    }
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn parsed(path: &str, format: Format, threads: Option<usize>) -> Args {
+        Args {
+            path: PathBuf::from(path),
+            format,
+            threads,
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parsed("data/day-01.txt", Format::Text, None),
+            parse_args(args(&["data/day-01.txt"])).unwrap()
+        );
+        assert!(matches!(parse_args(args(&[])), Err(AocError::Usage(_))));
+        assert!(matches!(
+            parse_args(args(&["a.txt", "b.txt"])),
+            Err(AocError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_format() {
+        let expected = parsed("a.txt", Format::Json, None);
+        assert_eq!(
+            expected,
+            parse_args(args(&["--format", "json", "a.txt"])).unwrap()
+        );
+        assert_eq!(
+            expected,
+            parse_args(args(&["a.txt", "--format=json"])).unwrap()
+        );
+        for bad in [
+            &["--format", "xml", "a.txt"][..],
+            &["a.txt", "--format"],
+            &["--verbose", "a.txt"],
+        ] {
+            assert!(matches!(parse_args(args(bad)), Err(AocError::Usage(_))));
+        }
+    }
+
+    #[test]
+    fn test_parse_args_threads() {
+        assert_eq!(
+            parsed("a.txt", Format::Text, Some(4)),
+            parse_args(args(&["--threads", "4", "a.txt"])).unwrap()
+        );
+        assert_eq!(
+            parsed("a.txt", Format::Json, Some(1)),
+            parse_args(args(&["a.txt", "--threads=1", "--format=json"])).unwrap()
+        );
+        for bad in [
+            &["--threads", "0", "a.txt"][..],
+            &["--threads", "many", "a.txt"],
+            &["a.txt", "--threads"],
+        ] {
+            assert!(matches!(parse_args(args(bad)), Err(AocError::Usage(_))));
+        }
+    }
+
+    #[test]
+    fn test_solve_str() {
+        assert_eq!(6, solve_str::<Sum>("1 2 3").unwrap());
+        assert!(matches!(
+            solve_str::<Sum>("1 x 3"),
+            Err(AocError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let res = try_run::<Sum>(1, 1, args(&["does/not/exist.txt"]));
+        assert!(matches!(res, Err(AocError::Io { .. })));
+    }
+}
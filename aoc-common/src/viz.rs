@@ -0,0 +1,401 @@
+//! Frames of grid simulations, for watching them in a terminal, dumping
+//! them to text files or drawing them as images.
+
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use crate::{AocError, Grid, Point};
+
+/// The eight basic terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    fn ansi_offset(self) -> u8 {
+        match self {
+            Colour::Black => 0,
+            Colour::Red => 1,
+            Colour::Green => 2,
+            Colour::Yellow => 3,
+            Colour::Blue => 4,
+            Colour::Magenta => 5,
+            Colour::Cyan => 6,
+            Colour::White => 7,
+        }
+    }
+
+    /// Roughly what a terminal shows for the colour.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [229, 229, 229],
+        }
+    }
+}
+
+const ALL_COLOURS: [Colour; 8] = [
+    Colour::Black,
+    Colour::Red,
+    Colour::Green,
+    Colour::Yellow,
+    Colour::Blue,
+    Colour::Magenta,
+    Colour::Cyan,
+    Colour::White,
+];
+
+pub type Rgb = [u8; 3];
+
+/// The colour each cell character is drawn in when a frame becomes an
+/// image. Characters without a colour of their own use `default`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    cells: Vec<(char, Rgb)>,
+    pub default: Rgb,
+}
+
+impl Default for Palette {
+    /// Black floor, grey walls and everything else white.
+    fn default() -> Self {
+        Palette {
+            cells: vec![('.', [0, 0, 0]), ('#', [128, 128, 128])],
+            default: [255, 255, 255],
+        }
+    }
+}
+
+impl Palette {
+    pub fn set(&mut self, c: char, rgb: Rgb) {
+        match self.cells.iter_mut().find(|(d, _)| *d == c) {
+            Some(cell) => cell.1 = rgb,
+            None => self.cells.push((c, rgb)),
+        }
+    }
+
+    pub fn get(&self, c: char) -> Rgb {
+        self.cells
+            .iter()
+            .find(|(d, _)| *d == c)
+            .map_or(self.default, |(_, rgb)| *rgb)
+    }
+
+    /// Every colour an image drawn with this palette can contain, overlays
+    /// included, without repeats.
+    pub fn colours(&self) -> Vec<Rgb> {
+        let mut colours = vec![self.default];
+        let all = self
+            .cells
+            .iter()
+            .map(|(_, rgb)| *rgb)
+            .chain(ALL_COLOURS.iter().map(|c| c.rgb()));
+        for rgb in all {
+            if !colours.contains(&rgb) {
+                colours.push(rgb);
+            }
+        }
+        colours
+    }
+}
+
+/// Parses `c=rrggbb` pairs separated by commas, e.g. `#=808080,O=c0a000`,
+/// on top of the default palette. `*=rrggbb` sets the default colour.
+impl FromStr for Palette {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+        for entry in s.split(',').filter(|entry| !entry.is_empty()) {
+            let invalid = || {
+                AocError::Usage(format!(
+                    "invalid palette entry '{}', expected a character, '=' and a colour like 808080",
+                    entry
+                ))
+            };
+            let (cell, hex) = entry.split_once('=').ok_or_else(invalid)?;
+            let mut chars = cell.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return Err(invalid());
+            };
+            let hex = hex.trim_start_matches('#');
+            if hex.len() != 6 || !hex.is_ascii() {
+                return Err(invalid());
+            }
+            let mut rgb = [0; 3];
+            for (i, channel) in rgb.iter_mut().enumerate() {
+                *channel = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+            }
+            match c {
+                '*' => palette.default = rgb,
+                c => palette.set(c, rgb),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// A frame drawn as pixels, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Rgb>,
+}
+
+/// How one cell is drawn on top of its character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Colour>,
+    pub bg: Option<Colour>,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut codes = vec![];
+        if let Some(fg) = self.fg {
+            codes.push((30 + fg.ansi_offset()).to_string());
+        }
+        if let Some(bg) = self.bg {
+            codes.push((40 + bg.ansi_offset()).to_string());
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// One step of a simulation: the grid, colours for some of its cells and a
+/// line of text to show underneath.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub caption: String,
+    styles: Vec<(Point, Style)>,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>) -> Self {
+        Frame {
+            grid,
+            caption: String::new(),
+            styles: vec![],
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Draws the characters at `points` in `colour`.
+    pub fn colour(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        for p in points {
+            self.style_mut(p).fg = Some(colour);
+        }
+        self
+    }
+
+    /// Draws the background behind `points` in `colour`.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        for p in points {
+            self.style_mut(p).bg = Some(colour);
+        }
+        self
+    }
+
+    /// The style a cell ends up with once every overlay is applied.
+    pub fn style(&self, p: Point) -> Style {
+        self.styles
+            .iter()
+            .find(|(q, _)| *q == p)
+            .map_or_else(Style::default, |(_, style)| *style)
+    }
+
+    fn style_mut(&mut self, p: Point) -> &mut Style {
+        match self.styles.iter().position(|(q, _)| *q == p) {
+            Some(i) => &mut self.styles[i].1,
+            None => {
+                self.styles.push((p, Style::default()));
+                &mut self.styles.last_mut().unwrap().1
+            }
+        }
+    }
+
+    /// The frame with ANSI colour codes, one line per row and `\r\n` line
+    /// endings so it also draws correctly in raw mode.
+    pub fn to_ansi(&self) -> String {
+        let mut styles = Grid::filled(self.grid.rows(), self.grid.cols(), Style::default());
+        for (p, style) in &self.styles {
+            if let Some(cell) = styles.get_mut(*p) {
+                *cell = *style;
+            }
+        }
+        let mut out = String::new();
+        for (p, c) in self.grid.iter() {
+            if p.col == 0 && p.row > 0 {
+                out.push_str("\r\n");
+            }
+            match styles[p] {
+                style if style == Style::default() => out.push(*c),
+                style => {
+                    out.push_str(&style.ansi());
+                    out.push(*c);
+                    out.push_str(RESET);
+                }
+            }
+        }
+        if !self.caption.is_empty() {
+            out.push_str("\r\n");
+            out.push_str(&self.caption);
+        }
+        out
+    }
+
+    /// Draws every cell as a `scale` by `scale` square. A highlighted cell
+    /// takes its highlight colour, a coloured one its colour, and any other
+    /// cell its colour from `palette`. The caption is left out.
+    pub fn to_image(&self, palette: &Palette, scale: u32) -> Image {
+        let scale = scale.max(1) as usize;
+        let width = self.grid.cols() * scale;
+        let mut cells = self.grid.map(|c| palette.get(*c));
+        for (p, style) in &self.styles {
+            if let (Some(cell), Some(colour)) = (cells.get_mut(*p), style.bg.or(style.fg)) {
+                *cell = colour.rgb();
+            }
+        }
+        let mut pixels = Vec::with_capacity(width * self.grid.rows() * scale);
+        for row in cells.iter_rows() {
+            let line: Vec<Rgb> = row
+                .iter()
+                .flat_map(|rgb| std::iter::repeat_n(*rgb, scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image {
+            width: width as u32,
+            height: (self.grid.rows() * scale) as u32,
+            pixels,
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// The plain text of the frame, with the caption on its own line after the
+/// grid. Colours are left out.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)?;
+        if !self.caption.is_empty() {
+            write!(f, "\n{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes each frame as plain text to `dir/frame-NNNN.txt`, creating `dir`
+/// if needed, and returns how many frames were written.
+pub fn dump(frames: impl IntoIterator<Item = Frame>, dir: &Path) -> Result<usize, AocError> {
+    let io_err = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| AocError::Io { path, source }
+    };
+    fs::create_dir_all(dir).map_err(io_err(dir))?;
+    let mut count = 0;
+    for (i, frame) in frames.into_iter().enumerate() {
+        let path = dir.join(format!("frame-{:04}.txt", i));
+        fs::write(&path, format!("{}\n", frame)).map_err(io_err(&path))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        Frame::new(Grid::parse("ab\ncd").unwrap())
+    }
+
+    #[test]
+    fn test_overlays() {
+        let frame = frame()
+            .colour([Point::new(0, 1)], Colour::Red)
+            .highlight([Point::new(0, 1), Point::new(1, 0)], Colour::Blue);
+        assert_eq!(
+            Style {
+                fg: Some(Colour::Red),
+                bg: Some(Colour::Blue)
+            },
+            frame.style(Point::new(0, 1))
+        );
+        assert_eq!(Style::default(), frame.style(Point::new(1, 1)));
+        assert_eq!("a\x1b[31;44mb\x1b[0m\r\n\x1b[44mc\x1b[0md", frame.to_ansi());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("ab\ncd", frame().to_string());
+        assert_eq!("ab\ncd\nstep 1", frame().with_caption("step 1").to_string());
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette: Palette = "O=c0a000,*=#0000ff,.=010203".parse().unwrap();
+        assert_eq!([0xc0, 0xa0, 0], palette.get('O'));
+        assert_eq!([1, 2, 3], palette.get('.'));
+        assert_eq!([128, 128, 128], palette.get('#'));
+        assert_eq!([0, 0, 255], palette.get('@'));
+        assert_eq!(palette.colours()[0], palette.default);
+        assert_eq!(12, palette.colours().len());
+        for bad in ["O", "OO=000000", "O=00000", "O=gg0000", "O=0000é"] {
+            assert!(
+                matches!(bad.parse::<Palette>(), Err(AocError::Usage(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_image() {
+        let palette: Palette = "a=010101,b=020202,c=030303,d=040404".parse().unwrap();
+        let image = frame()
+            .colour([Point::new(0, 1)], Colour::Red)
+            .highlight([Point::new(1, 1)], Colour::Blue)
+            .to_image(&palette, 2);
+        assert_eq!((4, 4), (image.width, image.height));
+        let (a, red, c, blue) = ([1, 1, 1], Colour::Red.rgb(), [3, 3, 3], Colour::Blue.rgb());
+        assert_eq!(
+            vec![a, a, red, red, a, a, red, red, c, c, blue, blue, c, c, blue, blue],
+            image.pixels
+        );
+    }
+
+    #[test]
+    fn test_dump() {
+        let dir = std::env::temp_dir().join(format!("aoc-viz-{}", std::process::id()));
+        let frames = (0..3).map(|i| frame().with_caption(format!("step {}", i)));
+        assert_eq!(3, dump(frames, &dir).unwrap());
+        assert_eq!(
+            "ab\ncd\nstep 2\n",
+            fs::read_to_string(dir.join("frame-0002.txt")).unwrap()
+        );
+        assert!(!dir.join("frame-0003.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
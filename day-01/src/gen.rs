@@ -0,0 +1,17 @@
+use aoc_common::gen::{GenRng, Rng, SliceRandom};
+
+/// `size` lines of two five-digit location IDs. About half of the right
+/// list is copied from the left one, so the similarity score is not zero.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.gen_range(10000..100000)).collect();
+    let mut out = String::new();
+    for l in &left {
+        let r = if rng.gen_bool(0.5) {
+            *left.choose(rng).unwrap()
+        } else {
+            rng.gen_range(10000..100000)
+        };
+        out.push_str(&format!("{}   {}\n", l, r));
+    }
+    out
+}
@@ -0,0 +1,6 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+pub mod reconcile;
+
+aoc_common::example_tests!();
@@ -0,0 +1,143 @@
+use crate::reconcile::{ListFormat, Reconciliation};
+use aoc_common::{AocError, Solution};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Reconciliation;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        // with more than two lists, every two of them are paired up
+        input.total_distance()
+    }
+}
+
+/// Any number of columns separated by whitespace, commas or tabs. Every row
+/// is data, so a bad first row is an error rather than a header.
+///
+/// Lines are held to the same rules as [`parse_complete`](aoc_common::parse_complete):
+/// `\r\n` reads as `\n` and one trailing newline is allowed, but a blank
+/// line or a stray `\r` is left over rather than skipped.
+pub(crate) fn parse_input(input: &str) -> Result<Reconciliation, AocError> {
+    let input = input.replace("\r\n", "\n");
+    let body = input.strip_suffix('\n').unwrap_or(&input);
+    let mut start = 0usize;
+    let blank_line = body.split('\n').find_map(|line| {
+        // the newline before a blank line is where the rows stop
+        let at = start.saturating_sub(1);
+        start += line.len() + 1;
+        (line.is_empty() && !body.is_empty()).then_some(at)
+    });
+    if let Some(at) = blank_line.into_iter().chain(body.find('\r')).min() {
+        return Err(AocError::trailing_at(body, &body[at..]));
+    }
+    let format = ListFormat {
+        header: Some(false),
+        ..ListFormat::default()
+    };
+    let lists = Reconciliation::parse_with(body, format)?;
+    if lists.is_empty() {
+        return Err(AocError::InvalidInput(
+            "there are no location IDs".to_string(),
+        ));
+    }
+    Ok(lists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        let numbers = vec![(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)];
+        assert_eq!(
+            Reconciliation::from_pairs(numbers),
+            parse_input(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_complete() {
+        let numbers = vec![(3, 4), (4, 3)];
+        assert_eq!(
+            Reconciliation::from_pairs(numbers),
+            Part1::parse("3   4\r\n4   3\r\n").unwrap()
+        );
+        assert!(matches!(
+            Part1::parse("3   4\n4   3\n2   x\n"),
+            Err(AocError::Parse { line: 3, .. })
+        ));
+        assert!(matches!(Part1::parse("\n"), Err(AocError::InvalidInput(_))));
+        // the puzzle input has no header, so a bad first row is not one
+        assert!(matches!(
+            Part1::parse("3 x\n4 3"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Part1::parse("left right\n4 3"),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+        // location IDs are never negative, so neither is either total
+        assert!(matches!(
+            Part1::parse("3 4\n-4 3"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let lists = Reconciliation::from_pairs([(3, 4), (4, 3), (2, 5)]);
+        assert_eq!(lists, Part1::parse("3   4\r\n4   3\r\n2   5").unwrap());
+        assert_eq!(lists, Part1::parse("3   4\r\n4   3\n2   5\r\n").unwrap());
+        assert!(matches!(
+            Part1::parse("3   4\r\n4   3\r\n\r\n"),
+            Err(AocError::TrailingInput {
+                line: 2,
+                column: 6,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Part1::parse("3   4\n\n4   3\n"),
+            Err(AocError::TrailingInput { line: 1, .. })
+        ));
+        assert!(matches!(
+            Part1::parse("\n3   4\n"),
+            Err(AocError::TrailingInput { line: 1, .. })
+        ));
+        assert!(matches!(
+            Part1::parse("3   4\n4   3\r2   5\n"),
+            Err(AocError::TrailingInput {
+                line: 2,
+                column: 6,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_many_columns() {
+        let input = "3,4,1\n4,3,4\n2,5,4\n";
+        assert_eq!(8, Part1::solve(&Part1::parse(input).unwrap()));
+    }
+}
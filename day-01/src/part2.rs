@@ -0,0 +1,56 @@
+use crate::{part1::parse_input, reconcile::Reconciliation};
+use aoc_common::{AocError, Solution};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Reconciliation;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+fn solve(lists: &Reconciliation) -> u64 {
+    // the right-hand counts are a histogram built while reading, rather
+    // than a scan per left ID, and more than two lists are compared pairwise
+    lists.total_similarity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = "3\t4\n4\t3\n";
+        let numbers = vec![(3, 4), (4, 3)];
+        let lists = Part2::parse(input).unwrap();
+        assert_eq!(["1", "2"], lists.names());
+        assert_eq!(2, lists.len());
+        assert_eq!(
+            Reconciliation::from_pairs(numbers).distance_matrix(),
+            lists.distance_matrix()
+        );
+        // headers are for aoc reconcile, not the puzzle input
+        assert!(Part2::parse("left\tright\n3\t4\n").is_err());
+    }
+
+    #[test]
+    fn test_mini() {
+        let numbers = vec![(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)];
+        let solution = 31;
+        assert_eq!(solution, solve(&Reconciliation::from_pairs(numbers)));
+    }
+
+    #[test]
+    fn test_many_columns() {
+        let input = "3 4 1\n4 3 4\n2 5 4\n";
+        assert_eq!(23, Part2::solve(&Part2::parse(input).unwrap()));
+    }
+}
@@ -0,0 +1,615 @@
+//! Reconciles location lists from counts of each ID rather than the lists
+//! themselves, so inputs of any length can be streamed in one line at a
+//! time and only the distinct IDs are kept in memory.
+//!
+//! Each column of the input is one list. The puzzle has two separated by
+//! spaces, but any number of columns can be compared, separated by commas,
+//! tabs or whitespace and optionally topped with a header row naming them.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    fs::File,
+    io::{BufRead, BufReader},
+    iter,
+    path::Path,
+    str::FromStr,
+};
+
+use aoc_common::AocError;
+
+/// What separates the columns of a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any run of spaces or tabs, as in the puzzle input
+    Whitespace,
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    /// The fields of `line`, trimmed. They are slices of `line`, so errors
+    /// can point at them.
+    fn split<'a>(&self, line: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            Delimiter::Whitespace => Box::new(line.split_whitespace()),
+            Delimiter::Comma => Box::new(line.split(',').map(str::trim)),
+            Delimiter::Tab => Box::new(line.split('\t').map(str::trim)),
+        }
+    }
+}
+
+impl FromStr for Delimiter {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "whitespace" | "space" => Ok(Delimiter::Whitespace),
+            "comma" | "," => Ok(Delimiter::Comma),
+            "tab" | "\t" => Ok(Delimiter::Tab),
+            _ => Err(AocError::Usage(format!(
+                "unknown delimiter '{}', expected whitespace, comma or tab",
+                s
+            ))),
+        }
+    }
+}
+
+/// How the rows of the input are laid out. Whatever is left as `None` is
+/// worked out from the first row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListFormat {
+    pub delimiter: Option<Delimiter>,
+    /// Whether the first row names the columns
+    pub header: Option<bool>,
+}
+
+impl ListFormat {
+    /// Fills in the blanks from the first row: commas win over tabs, which
+    /// win over whitespace, and the row is a header if none of its fields
+    /// is a number. A row with only some numbers is a malformed data row.
+    fn resolve(self, first_line: &str) -> (Delimiter, bool) {
+        let delimiter = self.delimiter.unwrap_or(if first_line.contains(',') {
+            Delimiter::Comma
+        } else if first_line.contains('\t') {
+            Delimiter::Tab
+        } else {
+            Delimiter::Whitespace
+        });
+        let header = self.header.unwrap_or_else(|| {
+            delimiter
+                .split(first_line)
+                .all(|field| field.parse::<u32>().is_err())
+        });
+        (delimiter, header)
+    }
+}
+
+/// The `n`th smallest IDs of two lists, which part 1 pairs up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub left: u32,
+    pub right: u32,
+    pub distance: u32,
+}
+
+/// What one ID of the left list adds to its similarity with the right
+/// list: the ID times how often it appears on the left times how often it
+/// appears on the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contribution {
+    pub id: u32,
+    pub left: usize,
+    pub right: usize,
+    pub score: u64,
+}
+
+/// How often each ID appears in each list.
+///
+/// Lists are picked by column index, and methods taking one panic if it is
+/// out of range.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Reconciliation {
+    names: Vec<String>,
+    columns: Vec<BTreeMap<u32, usize>>,
+    len: usize,
+}
+
+impl Reconciliation {
+    /// Two lists, as the puzzle has them.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut reconciliation = Reconciliation::with_columns(default_names(2));
+        for (left, right) in pairs {
+            reconciliation.push(&[left, right]);
+        }
+        reconciliation
+    }
+
+    fn with_columns(names: Vec<String>) -> Self {
+        Reconciliation {
+            columns: vec![BTreeMap::new(); names.len()],
+            names,
+            len: 0,
+        }
+    }
+
+    /// Reads the lists from `input`, laid out as `format` says.
+    pub fn parse_with(input: &str, format: ListFormat) -> Result<Self, AocError> {
+        Self::from_lines(input.lines().map(|line| Ok(line.to_string())), format)
+    }
+
+    /// Streams the lists from the file at `path`.
+    pub fn read(path: &Path, format: ListFormat) -> Result<Self, AocError> {
+        let file = File::open(path).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_reader(BufReader::new(file), path, format)
+    }
+
+    /// Streams the lists one line at a time. `path` only names the source
+    /// in errors.
+    pub fn from_reader(
+        reader: impl BufRead,
+        path: &Path,
+        format: ListFormat,
+    ) -> Result<Self, AocError> {
+        let lines = reader.lines().map(|line| {
+            line.map_err(|source| AocError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+        });
+        Self::from_lines(lines, format)
+    }
+
+    /// Blank lines are skipped, and every row must have as many columns as
+    /// the first, which needs at least two.
+    fn from_lines(
+        lines: impl Iterator<Item = Result<String, AocError>>,
+        format: ListFormat,
+    ) -> Result<Self, AocError> {
+        let mut resolved = None;
+        let mut reconciliation: Option<Reconciliation> = None;
+        let mut row = vec![];
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (delimiter, header) = *resolved.get_or_insert_with(|| format.resolve(&line));
+            let lists = match &mut reconciliation {
+                Some(lists) => lists,
+                None => {
+                    let fields: Vec<&str> = delimiter.split(&line).collect();
+                    if fields.len() < 2 {
+                        let end = &line[line.len()..];
+                        let expected = "at least two columns";
+                        return Err(on_line(AocError::parse_at(&line, end, expected), i + 1));
+                    }
+                    let names = if header {
+                        fields.iter().map(|name| name.to_string()).collect()
+                    } else {
+                        default_names(fields.len())
+                    };
+                    let lists = reconciliation.insert(Reconciliation::with_columns(names));
+                    if header {
+                        continue;
+                    }
+                    lists
+                }
+            };
+            row.clear();
+            for field in delimiter.split(&line) {
+                if row.len() == lists.columns() {
+                    return Err(on_line(AocError::trailing_at(&line, field), i + 1));
+                }
+                let id = field
+                    .parse()
+                    .map_err(|_| on_line(AocError::parse_at(&line, field, "a number"), i + 1))?;
+                row.push(id);
+            }
+            if row.len() < lists.columns() {
+                let expected = format!("{} columns", lists.columns());
+                let end = &line[line.len()..];
+                return Err(on_line(AocError::parse_at(&line, end, expected), i + 1));
+            }
+            lists.push(&row);
+        }
+        Ok(reconciliation.unwrap_or_default())
+    }
+
+    fn push(&mut self, row: &[u32]) {
+        for (column, id) in self.columns.iter_mut().zip(row) {
+            *column.entry(*id).or_default() += 1;
+        }
+        self.len += 1;
+    }
+
+    /// How many rows were read.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// How many lists there are.
+    pub fn columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// The names from the header row, or the column numbers from 1 up.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The index of the column called `name`, or numbered `name` from 1.
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name).or_else(|| {
+            name.parse::<usize>()
+                .ok()
+                .filter(|n| (1..=self.columns()).contains(n))
+                .map(|n| n - 1)
+        })
+    }
+
+    /// How often `id` appears in list `column`.
+    pub fn count(&self, column: usize, id: u32) -> usize {
+        self.columns[column].get(&id).copied().unwrap_or(0)
+    }
+
+    /// Every pair of lists `a` and `b` in order, smallest IDs first.
+    pub fn pairs(&self, a: usize, b: usize) -> impl Iterator<Item = Pair> + '_ {
+        expand(&self.columns[a])
+            .zip(expand(&self.columns[b]))
+            .map(|(left, right)| Pair {
+                left,
+                right,
+                distance: left.abs_diff(right),
+            })
+    }
+
+    /// The total distance between lists `a` and `b`.
+    pub fn distance(&self, a: usize, b: usize) -> u64 {
+        self.pairs(a, b).map(|pair| pair.distance as u64).sum()
+    }
+
+    /// What each ID of list `a` that also appears in list `b` adds to their
+    /// similarity score, smallest IDs first.
+    pub fn contributions(&self, a: usize, b: usize) -> impl Iterator<Item = Contribution> + '_ {
+        self.columns[a].iter().filter_map(move |(&id, &left)| {
+            let right = self.count(b, id);
+            (right > 0).then(|| Contribution {
+                id,
+                left,
+                right,
+                score: u64::from(id) * left as u64 * right as u64,
+            })
+        })
+    }
+
+    /// The similarity score of list `a` against list `b`. It is the same
+    /// both ways round, since every ID counts once for each match.
+    pub fn similarity(&self, a: usize, b: usize) -> u64 {
+        self.contributions(a, b).map(|c| c.score).sum()
+    }
+
+    /// The distance between every two lists, indexed `[a][b]`.
+    pub fn distance_matrix(&self) -> Vec<Vec<u64>> {
+        self.matrix(|a, b| self.distance(a, b))
+    }
+
+    /// The similarity of every two lists, indexed `[a][b]`. The diagonal is
+    /// each list against itself.
+    pub fn similarity_matrix(&self) -> Vec<Vec<u64>> {
+        self.matrix(|a, b| self.similarity(a, b))
+    }
+
+    fn matrix<T: Copy + Default>(&self, f: impl Fn(usize, usize) -> T) -> Vec<Vec<T>> {
+        let n = self.columns();
+        let mut matrix = vec![vec![T::default(); n]; n];
+        // both measures are symmetric, so each pair is worked out once
+        for (a, b) in (0..n).flat_map(|a| (a..n).map(move |b| (a, b))) {
+            matrix[a][b] = f(a, b);
+            matrix[b][a] = matrix[a][b];
+        }
+        matrix
+    }
+
+    /// The distance summed over every two different lists, which for two
+    /// lists is the part 1 answer.
+    pub fn total_distance(&self) -> u64 {
+        self.column_pairs().map(|(a, b)| self.distance(a, b)).sum()
+    }
+
+    /// The similarity summed over every two different lists, which for two
+    /// lists is the part 2 answer.
+    pub fn total_similarity(&self) -> u64 {
+        self.column_pairs()
+            .map(|(a, b)| self.similarity(a, b))
+            .sum()
+    }
+
+    fn column_pairs(&self) -> impl Iterator<Item = (usize, usize)> {
+        let n = self.columns();
+        (0..n).flat_map(move |a| (a + 1..n).map(move |b| (a, b)))
+    }
+
+    /// The `k` pairs of lists `a` and `b` furthest apart, largest first.
+    /// Ties go to the earlier pair.
+    pub fn top_distances(&self, a: usize, b: usize, k: usize) -> Vec<Pair> {
+        top_k(self.pairs(a, b), k, |pair| pair.distance)
+    }
+
+    /// The `k` IDs of list `a` adding the most to its similarity with list
+    /// `b`, largest first. Ties go to the smaller ID.
+    pub fn top_contributions(&self, a: usize, b: usize, k: usize) -> Vec<Contribution> {
+        top_k(self.contributions(a, b), k, |c| c.score)
+    }
+}
+
+fn default_names(columns: usize) -> Vec<String> {
+    (1..=columns).map(|n| n.to_string()).collect()
+}
+
+/// Each ID repeated as often as it was counted, in order.
+fn expand(counts: &BTreeMap<u32, usize>) -> impl Iterator<Item = u32> + '_ {
+    counts
+        .iter()
+        .flat_map(|(&id, &count)| iter::repeat_n(id, count))
+}
+
+/// The `k` items with the largest keys, largest first and earliest first
+/// among equal keys, holding no more than `k` of them at a time.
+fn top_k<T, K: Ord>(items: impl Iterator<Item = T>, k: usize, key: impl Fn(&T) -> K) -> Vec<T> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (i, item) in items.enumerate() {
+        // the heap is a min-heap on the key, so the smallest is evicted
+        heap.push(Reverse(Keyed(key(&item), i, item)));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    let mut top: Vec<Keyed<K, T>> = heap.into_iter().map(|Reverse(keyed)| keyed).collect();
+    top.sort_by(|a, b| b.cmp(a));
+    top.into_iter().map(|Keyed(_, _, item)| item).collect()
+}
+
+/// Orders items by key and then by how early they came, never by the item
+/// itself.
+struct Keyed<K, T>(K, usize, T);
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0).then(other.1.cmp(&self.1))
+    }
+}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+/// Moves an error from parsing a single line to that line of the input.
+fn on_line(err: AocError, line: usize) -> AocError {
+    match err {
+        AocError::Parse {
+            column,
+            snippet,
+            expected,
+            ..
+        } => AocError::Parse {
+            line,
+            column,
+            snippet,
+            expected,
+        },
+        AocError::TrailingInput {
+            column, snippet, ..
+        } => AocError::TrailingInput {
+            line,
+            column,
+            snippet,
+        },
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../data/examples/sample.txt");
+
+    /// Works the format out from the first row, as aoc reconcile does.
+    fn parse(input: &str) -> Result<Reconciliation, AocError> {
+        Reconciliation::parse_with(input, ListFormat::default())
+    }
+
+    const THREE: &str = "\
+north,south,east
+3,4,1
+4,3,4
+2,5,4
+";
+
+    fn sample() -> Reconciliation {
+        parse(SAMPLE).unwrap()
+    }
+
+    #[test]
+    fn test_totals() {
+        let reconciliation = sample();
+        assert_eq!(6, reconciliation.len());
+        assert_eq!(2, reconciliation.columns());
+        assert_eq!(11, reconciliation.distance(0, 1));
+        assert_eq!(31, reconciliation.similarity(0, 1));
+        assert_eq!(11, reconciliation.total_distance());
+        assert_eq!(31, reconciliation.total_similarity());
+        assert_eq!(3, reconciliation.count(1, 3));
+    }
+
+    #[test]
+    fn test_pairs() {
+        let distances: Vec<(u32, u32, u32)> = sample()
+            .pairs(0, 1)
+            .map(|p| (p.left, p.right, p.distance))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, 3, 2),
+                (2, 3, 1),
+                (3, 3, 0),
+                (3, 4, 1),
+                (3, 5, 2),
+                (4, 9, 5)
+            ],
+            distances
+        );
+    }
+
+    #[test]
+    fn test_top_distances() {
+        let top: Vec<u32> = sample()
+            .top_distances(0, 1, 3)
+            .iter()
+            .map(|p| p.distance)
+            .collect();
+        assert_eq!(vec![5, 2, 2], top);
+        // the tie between the two 2s goes to the earlier pair
+        assert_eq!(1, sample().top_distances(0, 1, 2)[1].left);
+        assert!(sample().top_distances(0, 1, 0).is_empty());
+        assert_eq!(6, sample().top_distances(0, 1, 10).len());
+    }
+
+    #[test]
+    fn test_top_contributions() {
+        let top = sample().top_contributions(0, 1, 2);
+        assert_eq!(
+            vec![
+                Contribution {
+                    id: 3,
+                    left: 3,
+                    right: 3,
+                    score: 27
+                },
+                Contribution {
+                    id: 4,
+                    left: 1,
+                    right: 1,
+                    score: 4
+                }
+            ],
+            top
+        );
+    }
+
+    #[test]
+    fn test_resolve_format() {
+        let detect = |line| ListFormat::default().resolve(line);
+        assert_eq!((Delimiter::Whitespace, false), detect("3   4"));
+        assert_eq!((Delimiter::Comma, true), detect("a, b"));
+        assert_eq!((Delimiter::Tab, false), detect("1\t2\t3"));
+        let given = ListFormat {
+            delimiter: Some(Delimiter::Whitespace),
+            header: None,
+        };
+        // split on whitespace, "1,2" is not a number but 3 is
+        assert_eq!((Delimiter::Whitespace, false), given.resolve("1,2 3"));
+        assert_eq!((Delimiter::Whitespace, true), given.resolve("a,b c"));
+        assert_eq!(Ok(Delimiter::Tab), "tab".parse().map_err(|_| ()));
+        assert!("semicolon".parse::<Delimiter>().is_err());
+    }
+
+    #[test]
+    fn test_many_columns() {
+        let lists = parse(THREE).unwrap();
+        assert_eq!(["north", "south", "east"], lists.names());
+        assert_eq!(Some(2), lists.column("east"));
+        assert_eq!(Some(0), lists.column("1"));
+        assert_eq!(None, lists.column("4"));
+        // sorted: north 2 3 4, south 3 4 5, east 1 4 4
+        assert_eq!(
+            vec![vec![0, 3, 2], vec![3, 0, 3], vec![2, 3, 0]],
+            lists.distance_matrix()
+        );
+        assert_eq!(
+            vec![vec![9, 7, 8], vec![7, 12, 8], vec![8, 8, 17]],
+            lists.similarity_matrix()
+        );
+        assert_eq!(8, lists.total_distance());
+        assert_eq!(23, lists.total_similarity());
+
+        let tabs = THREE.replace(',', "\t");
+        assert_eq!(lists, parse(&tabs).unwrap());
+        let no_header = parse("3 4 1\n4 3 4\n2 5 4\n").unwrap();
+        assert_eq!(["1", "2", "3"], no_header.names());
+        assert_eq!(lists.distance_matrix(), no_header.distance_matrix());
+    }
+
+    #[test]
+    fn test_given_format() {
+        // a numeric header is only skipped when the format says so
+        let format = ListFormat {
+            delimiter: None,
+            header: Some(true),
+        };
+        let input = "10,20\n1,2\n";
+        let lists = Reconciliation::parse_with(input, format).unwrap();
+        assert_eq!(1, lists.len());
+        assert_eq!(["10", "20"], lists.names());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            parse("1   2\r\n\n3 x\n"),
+            Err(AocError::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse("1   2\n3 4 5\n"),
+            Err(AocError::TrailingInput {
+                line: 2,
+                column: 5,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse("1,2,3\n4,5\n"),
+            Err(AocError::Parse { line: 2, ref expected, .. }) if expected == "3 columns"
+        ));
+        assert!(matches!(
+            parse("1,2,3\n1,,3\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse("\n17\n"),
+            Err(AocError::Parse { line: 2, ref expected, .. }) if expected == "at least two columns"
+        ));
+        // only some fields not being numbers makes a bad row, not a header
+        assert!(matches!(
+            parse("3 x\n4 3\n"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(parse("").unwrap().is_empty());
+    }
+}
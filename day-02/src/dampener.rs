@@ -0,0 +1,221 @@
+//! The Problem Dampener, generalised to any number of removable levels and
+//! any range of allowed steps.
+
+use std::ops::RangeInclusive;
+
+/// Which way the levels of a safe report go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 2] = [Direction::Increasing, Direction::Decreasing];
+
+    /// How far `from` moves to `to` in this direction, if it goes this way
+    /// at all.
+    pub(crate) fn step(self, from: u64, to: u64) -> Option<u64> {
+        match self {
+            Direction::Increasing => to.checked_sub(from),
+            Direction::Decreasing => from.checked_sub(to),
+        }
+    }
+}
+
+/// Decides which reports are safe: the levels all go the same way, each
+/// step between neighbours is in `steps`, and up to `max_removals` levels
+/// may be dropped to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dampener {
+    pub max_removals: usize,
+    pub steps: RangeInclusive<u64>,
+}
+
+impl Dampener {
+    /// The part 1 rules: no removals and steps of 1 to 3.
+    pub const STRICT: Dampener = Dampener::new(0, 1..=3);
+    /// The part 2 rules: one level may be removed.
+    pub const ONE_REMOVAL: Dampener = Dampener::new(1, 1..=3);
+
+    pub const fn new(max_removals: usize, steps: RangeInclusive<u64>) -> Self {
+        Dampener {
+            max_removals,
+            steps,
+        }
+    }
+
+    pub fn is_safe(&self, report: &[u64]) -> bool {
+        self.removals_needed(report).is_some()
+    }
+
+    pub fn count_safe<'a>(&self, reports: impl IntoIterator<Item = &'a [u64]>) -> usize {
+        reports
+            .into_iter()
+            .filter(|report| self.is_safe(report))
+            .count()
+    }
+
+    /// The fewest levels to remove for `report` to be safe, if that is no
+    /// more than `max_removals`.
+    pub fn removals_needed(&self, report: &[u64]) -> Option<usize> {
+        self.removals(report).map(|removed| removed.len())
+    }
+
+    /// The indices of the fewest levels to remove for `report` to be safe,
+    /// if that is no more than `max_removals`. Increasing reports win ties.
+    pub fn removals(&self, report: &[u64]) -> Option<Vec<usize>> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|direction| self.removals_going(report, direction))
+            .min_by_key(|removed| removed.len())
+    }
+
+    /// Like [`Dampener::removals`], with the levels going `direction`.
+    ///
+    /// `fewest[i]` is the fewest removals that leave a safe run of levels
+    /// ending with level `i` kept, and the kept level before it. Level `i`
+    /// follows the kept level `j` once the `i - j - 1` levels between them
+    /// are removed, so only the `max_removals + 1` levels before it are
+    /// worth looking back at.
+    pub fn removals_going(&self, report: &[u64], direction: Direction) -> Option<Vec<usize>> {
+        let k = self.max_removals;
+        let n = report.len();
+        if n == 0 {
+            return Some(vec![]);
+        }
+        let mut fewest: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(n);
+        for i in 0..n {
+            // keeping level i first means removing every level before it
+            let mut best = (i <= k).then_some((i, None));
+            for j in i.saturating_sub(k + 1)..i {
+                let Some((before, _)) = fewest[j] else {
+                    continue;
+                };
+                let fits = direction
+                    .step(report[j], report[i])
+                    .is_some_and(|step| self.steps.contains(&step));
+                let removals = before + (i - j - 1);
+                if fits && best.is_none_or(|(best, _)| removals < best) {
+                    best = Some((removals, Some(j)));
+                }
+            }
+            fewest.push(best.filter(|&(removals, _)| removals <= k));
+        }
+        // levels after the last one kept are removed too
+        let (_, last) = fewest
+            .iter()
+            .enumerate()
+            .filter_map(|(i, best)| best.map(|(removals, _)| (removals + (n - 1 - i), i)))
+            .filter(|&(removals, _)| removals <= k)
+            .min()?;
+        let mut kept = vec![false; n];
+        let mut at = Some(last);
+        while let Some(i) = at {
+            kept[i] = true;
+            at = fewest[i].and_then(|(_, before)| before);
+        }
+        Some((0..n).filter(|&i| !kept[i]).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::{rng, Rng};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const SAMPLE: [&[u64]; 6] = [
+        &[7, 6, 4, 2, 1],
+        &[1, 2, 7, 8, 9],
+        &[9, 7, 6, 2, 1],
+        &[1, 3, 2, 4, 5],
+        &[8, 6, 4, 4, 1],
+        &[1, 3, 6, 7, 9],
+    ];
+
+    /// Tries every way of removing up to `max_removals` levels.
+    fn naive_is_safe(dampener: &Dampener, report: &[u64]) -> bool {
+        let safe = |levels: &[u64]| {
+            Direction::ALL.into_iter().any(|direction| {
+                levels.windows(2).all(|w| {
+                    direction
+                        .step(w[0], w[1])
+                        .is_some_and(|step| dampener.steps.contains(&step))
+                })
+            })
+        };
+        let n = report.len();
+        (0u32..1 << n).any(|removed| {
+            let kept: Vec<u64> = (0..n)
+                .filter(|i| removed & (1 << i) == 0)
+                .map(|i| report[i])
+                .collect();
+            removed.count_ones() as usize <= dampener.max_removals && safe(&kept)
+        })
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(2, Dampener::STRICT.count_safe(SAMPLE));
+        assert_eq!(4, Dampener::ONE_REMOVAL.count_safe(SAMPLE));
+        assert_eq!(Some(vec![2]), Dampener::ONE_REMOVAL.removals(SAMPLE[3]));
+        assert_eq!(Some(vec![3]), Dampener::ONE_REMOVAL.removals(SAMPLE[4]));
+        assert_eq!(None, Dampener::ONE_REMOVAL.removals_needed(SAMPLE[1]));
+    }
+
+    #[test]
+    fn test_short_reports() {
+        // the direction cannot be voted on with only two steps
+        assert!(Dampener::ONE_REMOVAL.is_safe(&[8, 2, 7]));
+        assert!(Dampener::ONE_REMOVAL.is_safe(&[1, 9, 2]));
+        assert!(Dampener::STRICT.is_safe(&[5]));
+        assert!(!Dampener::STRICT.is_safe(&[5, 5]));
+        assert!(Dampener::ONE_REMOVAL.is_safe(&[5, 5]));
+    }
+
+    #[test]
+    fn test_first_level_bad() {
+        assert!(Dampener::ONE_REMOVAL.is_safe(&[20, 1, 2, 3, 4]));
+        assert!(Dampener::ONE_REMOVAL.is_safe(&[1, 9, 8, 7, 6]));
+        assert!(!Dampener::STRICT.is_safe(&[1, 9, 8, 7, 6]));
+    }
+
+    #[test]
+    fn test_more_removals_and_steps() {
+        let report = [1, 2, 50, 60, 3, 4];
+        assert!(!Dampener::ONE_REMOVAL.is_safe(&report));
+        assert_eq!(Some(vec![2, 3]), Dampener::new(2, 1..=3).removals(&report));
+        assert_eq!(
+            Some(0),
+            Dampener::new(0, 1..=60).removals_needed(&[1, 50, 60])
+        );
+        assert!(Dampener::new(0, 0..=3).is_safe(&[4, 4, 2]));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(
+            seed: u64,
+            len in 1..9usize,
+            max_removals in 0..4usize,
+            low in 0..3u64,
+            width in 0..4u64,
+        ) {
+            let mut rng = rng(seed);
+            let report: Vec<u64> = (0..len).map(|_| rng.gen_range(1..12)).collect();
+            let dampener = Dampener::new(max_removals, low..=low + width);
+            prop_assert_eq!(naive_is_safe(&dampener, &report), dampener.is_safe(&report));
+            if let Some(removed) = dampener.removals(&report) {
+                let kept: Vec<u64> = (0..len)
+                    .filter(|i| !removed.contains(i))
+                    .map(|i| report[i])
+                    .collect();
+                let strict = Dampener::new(0, dampener.steps.clone());
+                prop_assert!(removed.len() <= max_removals);
+                prop_assert!(strict.is_safe(&kept));
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Explains why each report is or is not safe under a [`Dampener`].
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::dampener::{Dampener, Direction};
+
+/// Why a pair of neighbouring levels breaks the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Reason {
+    /// The level stays the same.
+    ZeroStep,
+    /// The level goes the other way to the steps before it.
+    DirectionChange,
+    /// The level moves further than the dampener allows.
+    StepTooLarge,
+    /// The level moves less than the dampener allows, which only happens
+    /// when the allowed steps start above 1.
+    StepTooSmall,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Reason::ZeroStep => "zero step",
+            Reason::DirectionChange => "direction change",
+            Reason::StepTooLarge => "step too large",
+            Reason::StepTooSmall => "step too small",
+        })
+    }
+}
+
+/// A level of a report and where it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    pub index: usize,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnosis {
+    Safe,
+    /// Safe once these levels are removed, the fewest there can be.
+    SafeAfterRemoving {
+        removed: Vec<Level>,
+    },
+    /// Unsafe even with the removals allowed. The levels at
+    /// `first_violation_index` and the one after it are the first pair to
+    /// break the rules, going the way the first nonzero step goes.
+    Unsafe {
+        first_violation_index: usize,
+        reason: Reason,
+    },
+}
+
+pub fn diagnose(dampener: &Dampener, report: &[u64]) -> Diagnosis {
+    match dampener.removals(report) {
+        Some(removed) if removed.is_empty() => Diagnosis::Safe,
+        Some(removed) => Diagnosis::SafeAfterRemoving {
+            removed: removed
+                .into_iter()
+                .map(|index| Level {
+                    index,
+                    value: report[index],
+                })
+                .collect(),
+        },
+        None => {
+            let (first_violation_index, reason) = first_violation(dampener, report)
+                .expect("a report with no violations is safe without removals");
+            Diagnosis::Unsafe {
+                first_violation_index,
+                reason,
+            }
+        }
+    }
+}
+
+/// The first pair of levels to break the rules, by the index of its first
+/// level.
+fn first_violation(dampener: &Dampener, report: &[u64]) -> Option<(usize, Reason)> {
+    let direction = report
+        .windows(2)
+        .find(|w| w[0] != w[1])
+        .map(|w| {
+            if w[0] < w[1] {
+                Direction::Increasing
+            } else {
+                Direction::Decreasing
+            }
+        })
+        .unwrap_or(Direction::Increasing);
+    report.windows(2).enumerate().find_map(|(i, w)| {
+        let reason = match direction.step(w[0], w[1]) {
+            _ if w[0] == w[1] && dampener.steps.contains(&0) => return None,
+            _ if w[0] == w[1] => Reason::ZeroStep,
+            None => Reason::DirectionChange,
+            Some(step) if step > *dampener.steps.end() => Reason::StepTooLarge,
+            Some(step) if step < *dampener.steps.start() => Reason::StepTooSmall,
+            Some(_) => return None,
+        };
+        Some((i, reason))
+    })
+}
+
+/// How levels get highlighted by [`annotate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Removed levels in `[brackets]`, offending ones between `*stars*`.
+    Plain,
+    /// Removed levels in yellow, offending ones in red.
+    Ansi,
+}
+
+impl Style {
+    fn removed(self, value: u64) -> String {
+        match self {
+            Style::Plain => format!("[{}]", value),
+            Style::Ansi => format!("\x1b[33m{}\x1b[0m", value),
+        }
+    }
+
+    fn offending(self, value: u64) -> String {
+        match self {
+            Style::Plain => format!("*{}*", value),
+            Style::Ansi => format!("\x1b[1;31m{}\x1b[0m", value),
+        }
+    }
+}
+
+/// The report's levels with the ones `diagnosis` is about highlighted,
+/// followed by what it says, e.g. `1 *2* *7* 8 9  unsafe: step too large
+/// from 2 to 7`.
+pub fn annotate(report: &[u64], diagnosis: &Diagnosis, style: Style) -> String {
+    let levels: Vec<String> = report
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| match diagnosis {
+            Diagnosis::SafeAfterRemoving { removed } if removed.iter().any(|l| l.index == i) => {
+                style.removed(value)
+            }
+            Diagnosis::Unsafe {
+                first_violation_index: at,
+                ..
+            } if i == *at || i == at + 1 => style.offending(value),
+            _ => value.to_string(),
+        })
+        .collect();
+    let verdict = match diagnosis {
+        Diagnosis::Safe => "safe".to_string(),
+        Diagnosis::SafeAfterRemoving { removed } => {
+            let removed: Vec<String> = removed
+                .iter()
+                .map(|l| format!("{} at {}", l.value, l.index))
+                .collect();
+            format!("safe after removing {}", removed.join(", "))
+        }
+        Diagnosis::Unsafe {
+            first_violation_index: at,
+            reason,
+        } => format!(
+            "unsafe: {} from {} to {}",
+            reason,
+            report[*at],
+            report[at + 1]
+        ),
+    };
+    format!("{}  {}", levels.join(" "), verdict)
+}
+
+/// How many reports got each kind of diagnosis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub safe: usize,
+    pub safe_after_removing: usize,
+    pub unsafe_reports: BTreeMap<Reason, usize>,
+}
+
+impl Summary {
+    pub fn add(&mut self, diagnosis: &Diagnosis) {
+        match diagnosis {
+            Diagnosis::Safe => self.safe += 1,
+            Diagnosis::SafeAfterRemoving { .. } => self.safe_after_removing += 1,
+            Diagnosis::Unsafe { reason, .. } => {
+                *self.unsafe_reports.entry(*reason).or_default() += 1
+            }
+        }
+    }
+
+    pub fn unsafe_count(&self) -> usize {
+        self.unsafe_reports.values().sum()
+    }
+}
+
+impl<'a> FromIterator<&'a Diagnosis> for Summary {
+    fn from_iter<I: IntoIterator<Item = &'a Diagnosis>>(diagnoses: I) -> Self {
+        let mut summary = Summary::default();
+        for diagnosis in diagnoses {
+            summary.add(diagnosis);
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [&[u64]; 6] = [
+        &[7, 6, 4, 2, 1],
+        &[1, 2, 7, 8, 9],
+        &[9, 7, 6, 2, 1],
+        &[1, 3, 2, 4, 5],
+        &[8, 6, 4, 4, 1],
+        &[1, 3, 6, 7, 9],
+    ];
+
+    fn unsafe_at(first_violation_index: usize, reason: Reason) -> Diagnosis {
+        Diagnosis::Unsafe {
+            first_violation_index,
+            reason,
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let diagnoses: Vec<Diagnosis> = SAMPLE
+            .iter()
+            .map(|report| diagnose(&Dampener::ONE_REMOVAL, report))
+            .collect();
+        assert_eq!(
+            vec![
+                Diagnosis::Safe,
+                unsafe_at(1, Reason::StepTooLarge),
+                unsafe_at(2, Reason::StepTooLarge),
+                Diagnosis::SafeAfterRemoving {
+                    removed: vec![Level { index: 2, value: 2 }]
+                },
+                Diagnosis::SafeAfterRemoving {
+                    removed: vec![Level { index: 3, value: 4 }]
+                },
+                Diagnosis::Safe,
+            ],
+            diagnoses
+        );
+        let summary: Summary = diagnoses.iter().collect();
+        assert_eq!(2, summary.safe);
+        assert_eq!(2, summary.safe_after_removing);
+        assert_eq!(
+            BTreeMap::from([(Reason::StepTooLarge, 2)]),
+            summary.unsafe_reports
+        );
+    }
+
+    #[test]
+    fn test_reasons() {
+        let strict = Dampener::STRICT;
+        assert_eq!(
+            unsafe_at(2, Reason::ZeroStep),
+            diagnose(&strict, &[8, 6, 4, 4, 1])
+        );
+        assert_eq!(
+            unsafe_at(1, Reason::DirectionChange),
+            diagnose(&strict, &[1, 3, 2, 4, 5])
+        );
+        // the direction comes from the first step that goes anywhere
+        assert_eq!(
+            unsafe_at(0, Reason::ZeroStep),
+            diagnose(&strict, &[5, 5, 4, 6])
+        );
+        assert_eq!(
+            unsafe_at(0, Reason::StepTooSmall),
+            diagnose(&Dampener::new(0, 2..=3), &[1, 2, 4])
+        );
+    }
+
+    #[test]
+    fn test_annotate() {
+        let report = SAMPLE[1];
+        let diagnosis = diagnose(&Dampener::ONE_REMOVAL, report);
+        assert_eq!(
+            "1 *2* *7* 8 9  unsafe: step too large from 2 to 7",
+            annotate(report, &diagnosis, Style::Plain)
+        );
+        let report = SAMPLE[3];
+        let diagnosis = diagnose(&Dampener::ONE_REMOVAL, report);
+        assert_eq!(
+            "1 3 [2] 4 5  safe after removing 2 at 2",
+            annotate(report, &diagnosis, Style::Plain)
+        );
+        assert_eq!(
+            "1 3 \x1b[33m2\x1b[0m 4 5  safe after removing 2 at 2",
+            annotate(report, &diagnosis, Style::Ansi)
+        );
+        assert_eq!(
+            "7 6 4 2 1  safe",
+            annotate(SAMPLE[0], &Diagnosis::Safe, Style::Plain)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// `size` reports of five to eight levels. Most are steady climbs or
+/// descents of one to three, some with a single bad level dropped in, and
+/// the rest are noise.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let len = rng.gen_range(5..=8);
+        let mut levels: Vec<i64> = if rng.gen_bool(0.7) {
+            let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level = rng.gen_range(30..70);
+            (0..len)
+                .map(|_| {
+                    level += sign * rng.gen_range(1..=3);
+                    level
+                })
+                .collect()
+        } else {
+            (0..len).map(|_| rng.gen_range(1..100)).collect()
+        };
+        if rng.gen_bool(0.3) {
+            let i = rng.gen_range(0..len);
+            levels[i] = rng.gen_range(1..100);
+        }
+        let line: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+        out.push_str(&line.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_dampener_only_adds_safe_reports() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 20);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            assert!(part1 <= Part2::solve(&Part2::parse(&text).unwrap()));
+        }
+    }
+}
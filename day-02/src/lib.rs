@@ -0,0 +1,7 @@
+pub mod dampener;
+pub mod diagnose;
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+aoc_common::example_tests!();
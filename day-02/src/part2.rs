@@ -0,0 +1,48 @@
+use crate::dampener::Dampener;
+use aoc_common::{parse_complete, AocError, Solution};
+use nom::{
+    character::complete::{newline, space1, u64},
+    multi::separated_list1,
+    IResult,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<Vec<u64>>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let slices: Vec<&[u64]> = input.iter().map(|v| v.as_slice()).collect();
+        solve(&slices)
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(space1, u64)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+fn solve(data: &[&[u64]]) -> u64 {
+    Dampener::ONE_REMOVAL.count_safe(data.iter().copied()) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let input = r"49 52 53 55 58 59 61 61";
+        let numbers = vec![49, 52, 53, 55, 58, 59, 61, 61];
+        let (_, parsed_result) = parse_line(input).unwrap();
+        assert_eq!(numbers, parsed_result);
+    }
+}
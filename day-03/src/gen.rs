@@ -0,0 +1,52 @@
+use aoc_common::gen::{GenRng, Rng, SliceRandom};
+
+const JUNK: &[char] = &[
+    'x', 'm', 'u', 'l', 'd', 'o', 'n', '\'', 't', '(', ')', ',', '[', ']', '!', '@', '^', '&', '*',
+    '?', '%', '$', '#', '+', '-', '_', '<', '>', ' ', '/', '{', '}', ':', ';',
+];
+
+/// `size` lines of corrupted memory: real `mul(X,Y)` instructions with one
+/// to three digit operands, near misses like `mul(4*` and `mul ( 2 , 4 )`,
+/// `do()` and `don't()`, all scattered through noise.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(10..30) {
+            let a = rng.gen_range(1..1000);
+            let b = rng.gen_range(1..1000);
+            let token = match rng.gen_range(0..10) {
+                0..=3 => format!("mul({},{})", a, b),
+                4 => format!("mul({},{}]", a, b),
+                5 => format!("mul ( {} , {} )", a, b),
+                6 => format!("mul({}*", a),
+                7 => "do()".to_string(),
+                8 => "don't()".to_string(),
+                _ => String::new(),
+            };
+            out.push_str(&token);
+            for _ in 0..rng.gen_range(0..4) {
+                out.push(*JUNK.choose(rng).unwrap());
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_disabling_only_drops_products() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 10);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            // part 2 only leaves instructions out
+            assert!(Part2::solve(&Part2::parse(&text).unwrap()) <= part1);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Runs the instructions read by the [`Lexer`](crate::lexer::Lexer).
+
+use crate::lexer::Token;
+
+/// Adds up the products of the `mul` instructions. With conditionals on,
+/// `don't()` switches them off and `do()` back on again, for the rest of
+/// the memory rather than just the rest of the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpreter {
+    conditionals: bool,
+    enabled: bool,
+    total: u128,
+}
+
+impl Interpreter {
+    /// `mul` instructions start enabled.
+    pub fn new(conditionals: bool) -> Self {
+        Interpreter {
+            conditionals,
+            enabled: true,
+            total: 0,
+        }
+    }
+
+    pub fn step(&mut self, token: Token) {
+        match token {
+            Token::Mul(a, b) if self.enabled => self.total += u128::from(a) * u128::from(b),
+            Token::Mul(..) => {}
+            Token::Do if self.conditionals => self.enabled = true,
+            Token::Dont if self.conditionals => self.enabled = false,
+            Token::Do | Token::Dont => {}
+        }
+    }
+
+    pub fn run(&mut self, tokens: impl IntoIterator<Item = Token>) -> u128 {
+        for token in tokens {
+            self.step(token);
+        }
+        self.total
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn run(memory: &str, conditionals: bool) -> u128 {
+        let tokens = Lexer::new(memory).max_digits(None).map(|s| s.token);
+        Interpreter::new(conditionals).run(tokens)
+    }
+
+    #[test]
+    fn test_sample() {
+        let memory = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(161, run(memory, false));
+        assert_eq!(48, run(memory, true));
+    }
+
+    #[test]
+    fn test_state_carries_across_lines() {
+        let memory = "mul(1,2)don't()\nmul(3,4)\ndo()mul(5,6)\n";
+        assert_eq!(2 + 12 + 30, run(memory, false));
+        assert_eq!(2 + 30, run(memory, true));
+
+        let mut interpreter = Interpreter::new(true);
+        interpreter.run([Token::Mul(2, 2), Token::Dont]);
+        assert!(!interpreter.enabled());
+        interpreter.step(Token::Mul(7, 7));
+        assert_eq!(4, interpreter.total());
+    }
+
+    #[test]
+    fn test_no_overflow() {
+        let memory = "mul(4294967295,4294967295)".repeat(3);
+        assert_eq!(3 * u128::from(u32::MAX).pow(2), run(&memory, false));
+    }
+}
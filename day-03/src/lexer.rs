@@ -0,0 +1,180 @@
+//! Picks the instructions out of corrupted memory in one pass.
+
+use std::ops::Range;
+
+/// The most digits the puzzle allows in a `mul` operand.
+pub const PUZZLE_DIGITS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    /// `mul(a,b)`
+    Mul(u32, u32),
+    /// `do()`
+    Do,
+    /// `don't()`
+    Dont,
+}
+
+/// A token and the bytes of memory it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned {
+    pub token: Token,
+    pub span: Range<usize>,
+}
+
+/// Yields every instruction in the memory in order, skipping the noise
+/// between them. Anything that is not exactly an instruction, such as
+/// `mul ( 2 , 4 )` or `mul(4*`, is noise; a `mul` inside it can still be
+/// read.
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    memory: &'a [u8],
+    at: usize,
+    max_digits: Option<usize>,
+}
+
+impl<'a> Lexer<'a> {
+    /// Reads operands of up to [`PUZZLE_DIGITS`] digits, as the puzzle
+    /// does.
+    pub fn new(memory: &'a str) -> Self {
+        Lexer {
+            memory: memory.as_bytes(),
+            at: 0,
+            max_digits: Some(PUZZLE_DIGITS),
+        }
+    }
+
+    /// Treats a `mul` with an operand longer than `max_digits` as noise.
+    /// `None` reads operands of any length that fits in a `u32`.
+    pub fn max_digits(self, max_digits: Option<usize>) -> Self {
+        Lexer { max_digits, ..self }
+    }
+
+    /// The token starting at `self.at` and its length, if there is one.
+    fn token(&self) -> Option<(Token, usize)> {
+        let rest = &self.memory[self.at..];
+        if rest.starts_with(b"do()") {
+            return Some((Token::Do, 4));
+        }
+        if rest.starts_with(b"don't()") {
+            return Some((Token::Dont, 7));
+        }
+        let rest = rest.strip_prefix(b"mul(")?;
+        let (a, rest) = self.operand(rest)?;
+        let rest = rest.strip_prefix(b",")?;
+        let (b, rest) = self.operand(rest)?;
+        rest.strip_prefix(b")")?;
+        let len = self.memory.len() - self.at - rest.len() + 1;
+        Some((Token::Mul(a, b), len))
+    }
+
+    fn operand<'b>(&self, bytes: &'b [u8]) -> Option<(u32, &'b [u8])> {
+        let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || self.max_digits.is_some_and(|max| digits > max) {
+            return None;
+        }
+        let (digits, rest) = bytes.split_at(digits);
+        // the digits are ASCII, so always UTF-8
+        let value = std::str::from_utf8(digits).ok()?.parse().ok()?;
+        Some((value, rest))
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Spanned;
+
+    fn next(&mut self) -> Option<Spanned> {
+        while self.at < self.memory.len() {
+            if let Some((token, len)) = self.token() {
+                let span = self.at..self.at + len;
+                self.at += len;
+                return Some(Spanned { token, span });
+            }
+            self.at += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(memory: &str, max_digits: Option<usize>) -> Vec<Token> {
+        Lexer::new(memory)
+            .max_digits(max_digits)
+            .map(|spanned| spanned.token)
+            .collect()
+    }
+
+    #[test]
+    fn test_sample() {
+        let memory = r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(
+            vec![
+                Token::Mul(2, 4),
+                Token::Mul(5, 5),
+                Token::Mul(11, 8),
+                Token::Mul(8, 5)
+            ],
+            tokens(memory, None)
+        );
+    }
+
+    #[test]
+    fn test_conditionals_and_spans() {
+        let memory = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let spanned: Vec<Spanned> = Lexer::new(memory).max_digits(None).collect();
+        let tokens: Vec<Token> = spanned.iter().map(|s| s.token).collect();
+        assert_eq!(
+            vec![
+                Token::Mul(2, 4),
+                Token::Dont,
+                Token::Mul(5, 5),
+                Token::Mul(11, 8),
+                Token::Do,
+                Token::Mul(8, 5)
+            ],
+            tokens
+        );
+        assert_eq!("don't()", &memory[spanned[1].span.clone()]);
+        assert_eq!("mul(8,5)", &memory[spanned[5].span.clone()]);
+    }
+
+    #[test]
+    fn test_near_misses() {
+        assert_eq!(
+            Vec::<Token>::new(),
+            tokens(
+                "mul ( 2 , 4 ) mul(4* mul(,3) mul(-2,3) mul(+2,3) don't do(",
+                None
+            )
+        );
+        // a broken instruction does not hide the one starting inside it
+        assert_eq!(vec![Token::Mul(1, 2)], tokens("mul(3,mul(1,2)", None));
+        assert_eq!(vec![Token::Do], tokens("do(do()", None));
+    }
+
+    #[test]
+    fn test_max_digits() {
+        let memory = "mul(1234,5)mul(999,1)mul(0004,2)";
+        assert_eq!(
+            vec![Token::Mul(1234, 5), Token::Mul(999, 1), Token::Mul(4, 2)],
+            tokens(memory, None)
+        );
+        assert_eq!(
+            vec![Token::Mul(999, 1)],
+            tokens(memory, Some(PUZZLE_DIGITS))
+        );
+        // the puzzle's limit is the default
+        let default: Vec<Token> = Lexer::new(memory).map(|s| s.token).collect();
+        assert_eq!(vec![Token::Mul(999, 1)], default);
+        // too big for a u32 is noise, whatever the limit
+        assert_eq!(Vec::<Token>::new(), tokens("mul(99999999999,1)", None));
+    }
+
+    #[test]
+    fn test_multibyte_noise() {
+        assert_eq!(vec![Token::Mul(2, 3)], tokens("é✓mul(2,3)é", None));
+    }
+}
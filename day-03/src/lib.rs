@@ -0,0 +1,7 @@
+pub mod gen;
+pub mod interpreter;
+pub mod lexer;
+pub mod part1;
+pub mod part2;
+
+aoc_common::example_tests!();
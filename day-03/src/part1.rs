@@ -0,0 +1,43 @@
+use crate::{
+    interpreter::Interpreter,
+    lexer::{Lexer, Token, PUZZLE_DIGITS},
+};
+use aoc_common::{AocError, Solution};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Vec<Token>;
+    type Output = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_with(input, Some(PUZZLE_DIGITS)))
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        Interpreter::new(false).run(input.iter().copied())
+    }
+}
+
+/// Every instruction in the memory, with operands of up to `max_digits`
+/// digits, or any length for `None`. [`Solution::parse`] keeps to the
+/// puzzle's limit. Anything else is just corruption, so there is nothing
+/// to reject.
+pub fn parse_with(input: &str, max_digits: Option<usize>) -> Vec<Token> {
+    Lexer::new(input)
+        .max_digits(max_digits)
+        .map(|spanned| spanned.token)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_limit() {
+        let input = "mul(1000,2)mul(3,4)\n";
+        assert_eq!(12, Part1::solve(&Part1::parse(input).unwrap()));
+        assert_eq!(2012, Part1::solve(&parse_with(input, None)));
+    }
+}
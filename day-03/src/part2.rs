@@ -0,0 +1,33 @@
+use crate::{
+    interpreter::Interpreter,
+    lexer::{Token, PUZZLE_DIGITS},
+    part1::parse_with,
+};
+use aoc_common::{AocError, Solution};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<Token>;
+    type Output = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_with(input, Some(PUZZLE_DIGITS)))
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        Interpreter::new(true).run(input.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_limit() {
+        let input = "mul(1000,2)don't()\nmul(5,5)do()mul(3,4)\n";
+        assert_eq!(12, Part2::solve(&Part2::parse(input).unwrap()));
+        assert_eq!(2012, Part2::solve(&parse_with(input, None)));
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_common::gen::{self, GenRng, SliceRandom};
+
+/// A `size` by `size` word search made only of the letters in XMAS.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    gen::grid(rng, size, size, |rng| {
+        *['X', 'M', 'A', 'S'].choose(rng).unwrap()
+    })
+}
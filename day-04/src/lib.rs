@@ -0,0 +1,5 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+aoc_common::example_tests!();
@@ -0,0 +1,46 @@
+use aoc_common::{AocError, Direction, Grid, Solution};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+fn solve(grid: &Grid<char>) -> usize {
+    grid.find_all(&'X')
+        .map(|p| {
+            Direction::ALL
+                .iter()
+                .filter(|dir| {
+                    grid.ray(p, dir.offset())
+                        .take(4)
+                        .map(|(_, c)| *c)
+                        .eq("XMAS".chars())
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = r"ab
+cd";
+        let answer = Grid::from_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+        let result = Grid::parse(input).unwrap();
+        assert_eq!(answer, result);
+    }
+}
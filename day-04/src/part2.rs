@@ -0,0 +1,44 @@
+use aoc_common::{AocError, Direction, Grid, Solution};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+const ORIENTATIONS: [Direction; 4] = [Direction::SE, Direction::NW, Direction::SW, Direction::NE];
+
+fn solve(grid: &Grid<char>) -> usize {
+    grid.find_all(&'A')
+        .filter(|p| {
+            let mas_count = ORIENTATIONS
+                .iter()
+                .filter(|or| grid.get(*p + **or) == Some(&'M') && grid.get(*p - **or) == Some(&'S'))
+                .count();
+            mas_count >= 2
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = r"ab
+cd";
+        let answer = Grid::from_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+        let result = Grid::parse(input).unwrap();
+        assert_eq!(answer, result);
+    }
+}
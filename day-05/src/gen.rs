@@ -0,0 +1,59 @@
+use aoc_common::gen::{GenRng, Rng, SliceRandom};
+
+/// Ordering rules for up to 49 two-digit pages and `size` updates.
+///
+/// The pages are ranked in a random order and there is a rule for every
+/// pair, like in the real input, so the rules agree with each other and
+/// every update has exactly one correct order. Updates have an odd number
+/// of pages so there is always a middle one; about half are in order.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut pages: Vec<usize> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(size.clamp(5, 49));
+
+    let mut rules = vec![];
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let rank = |page: &usize| pages.iter().position(|p| p == page);
+    let mut updates = vec![];
+    for _ in 0..size {
+        let len = rng.gen_range(1..=pages.len().min(23).div_ceil(2)) * 2 - 1;
+        let mut update: Vec<usize> = pages.choose_multiple(rng, len).copied().collect();
+        if rng.gen_bool(0.5) {
+            update.sort_by_key(rank);
+        }
+        let update: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+        updates.push(update.join(","));
+    }
+    format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::gen::generate;
+
+    use super::*;
+
+    #[test]
+    fn test_rules_are_consistent() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 10);
+            let (rules, updates) = text.split_once("\n\n").unwrap();
+            let rules: HashSet<(&str, &str)> = rules
+                .lines()
+                .map(|rule| rule.split_once('|').unwrap())
+                .collect();
+            // one rule for every pair of the ten pages, never both ways round
+            assert_eq!(45, rules.len());
+            assert!(rules.iter().all(|&(a, b)| !rules.contains(&(b, a))));
+            assert!(updates.lines().all(|u| u.split(',').count() % 2 == 1));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod gen;
+pub mod part1;
+
+aoc_common::example_tests!();
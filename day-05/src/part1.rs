@@ -0,0 +1,87 @@
+use aoc_common::{parse_complete, AocError, Solution};
+use std::collections::HashMap;
+
+use nom::{
+    bytes::complete::{take_till, take_until},
+    character::complete::{char, newline, u64},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+type Rules = HashMap<(usize, usize), bool>;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = (Rules, Vec<Vec<usize>>);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let (rules_map, pages) = input;
+        let slices: Vec<&[usize]> = pages.iter().map(|v| v.as_slice()).collect();
+        solve(rules_map, &slices)
+    }
+}
+
+fn solve(rules: &Rules, pages: &[&[usize]]) -> usize {
+    // we need the sum of the middle elements of the successful ones
+
+    pages
+        .iter()
+        .filter(|p| check_pages_legit(p, rules))
+        .map(|p| p[p.len() / 2])
+        .sum()
+}
+
+fn parse_rule(input: &str) -> IResult<&str, (usize, usize)> {
+    let (input, (p1, p2)) = separated_pair(u64, char('|'), u64)(input)?;
+    Ok((input, (p1 as usize, p2 as usize)))
+}
+
+fn parse_pages(input: &str) -> IResult<&str, Vec<usize>> {
+    let (input, nums) = separated_list1(char(','), u64)(input)?;
+    let nums = nums.into_iter().map(|x| x as usize).collect();
+    Ok((input, nums))
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Rules, Vec<Vec<usize>>)> {
+    let (page_str, rules_str) = take_until("\n\n")(input)?;
+    let (_, rules) = separated_list1(newline, parse_rule)(rules_str)?;
+    let rule_map = construct_rules_dict(&rules);
+
+    // remove the double newline from page_str
+    let (page_str, _) = take_till(|c: char| c.is_numeric())(page_str)?;
+
+    let (_, pages) = separated_list1(newline, parse_pages)(page_str)?;
+    Ok(("", (rule_map, pages)))
+}
+
+fn construct_rules_dict(rules: &[(usize, usize)]) -> Rules {
+    // let mut rules_dict
+    let mut rules_map = Rules::new();
+    // let rev_iter = rules.iter().map(|(x,y)| (y,x));
+    for (x, y) in rules.iter() {
+        rules_map.insert((*x, *y), true);
+        rules_map.insert((*y, *x), false);
+    }
+    rules_map
+}
+
+fn check_pages_legit(pages: &[usize], rules: &Rules) -> bool {
+    for i in 0..pages.len() {
+        for j in i..pages.len() {
+            // check every pair to see if it has a rule or not. if the rule returns false then return false
+            if let Some(b) = rules.get(&(pages[i], pages[j])) {
+                if !b {
+                    return false;
+                }
+            };
+        }
+    }
+    true
+}
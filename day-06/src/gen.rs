@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    gen::{self, GenRng, Rng},
+    Direction, Grid,
+};
+
+/// A `size` by `size` lab with scattered obstructions and the guard facing
+/// up.
+///
+/// Part 1 needs the guard to walk off the map, so labs where it ends up
+/// going round in circles are thrown away and drawn again, as are labs
+/// where it has to turn twice in one spot.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let size = size.max(2);
+    loop {
+        let mut text = gen::grid(
+            rng,
+            size,
+            size,
+            |rng| {
+                if rng.gen_bool(0.1) {
+                    '#'
+                } else {
+                    '.'
+                }
+            },
+        );
+        let free: Vec<usize> = text.match_indices('.').map(|(i, _)| i).collect();
+        if free.is_empty() {
+            continue;
+        }
+        let start = free[rng.gen_range(0..free.len())];
+        text.replace_range(start..start + 1, "^");
+        if leaves(&Grid::parse(&text).expect("generated rows have the same length")) {
+            return text;
+        }
+    }
+}
+
+/// Whether the guard walks off the map without looping or getting boxed in.
+fn leaves(grid: &Grid<char>) -> bool {
+    let mut p = grid.find(&'^').expect("the guard was placed");
+    let mut dir = Direction::N;
+    let mut seen = HashSet::new();
+    while seen.insert((p, dir)) {
+        let next = p + dir;
+        match grid.get(next) {
+            None => return true,
+            Some('#') => {
+                dir = dir.turn_right();
+                if grid.get(p + dir) == Some(&'#') {
+                    return false;
+                }
+            }
+            Some(_) => p = next,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+
+    use super::*;
+
+    #[test]
+    fn test_one_guard() {
+        for seed in 0..5 {
+            assert_eq!(1, generate(input, seed, 12).matches('^').count());
+        }
+    }
+
+    #[test]
+    fn test_leaves() {
+        assert!(leaves(&Grid::parse("..\n^.").unwrap()));
+        assert!(!leaves(&Grid::parse(".#..\n...#\n#^..\n..#.").unwrap()));
+        assert!(!leaves(&Grid::parse("#.\n^#").unwrap()));
+    }
+}
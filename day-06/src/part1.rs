@@ -0,0 +1,136 @@
+use aoc_common::{
+    viz::{Colour, Frame},
+    AocError, Direction, Grid, Point, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(input)?;
+        if grid.find(&'^').is_none() {
+            return Err(AocError::InvalidInput(
+                "the map has no guard '^'".to_string(),
+            ));
+        }
+        Ok(grid)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+fn get_start_point(grid: &Grid<char>) -> Point {
+    grid.find(&'^').expect("bro where is the start point fr fr")
+}
+
+fn solve(grid: &Grid<char>) -> usize {
+    let mut tracker = Grid::filled(grid.rows(), grid.cols(), false);
+    let mut seen = HashSet::new();
+    let mut p = get_start_point(grid);
+    let mut dir = Direction::N;
+    // a guard walking in a loop has seen every cell once it repeats itself
+    while seen.insert((p, dir)) {
+        tracker[p] = true;
+        // turn one step at a time, the way after a turn can be blocked too
+        match grid.get(p + dir) {
+            None => break,
+            Some('#') => dir = dir.turn_right(),
+            Some(_) => p += dir,
+        }
+    }
+    // out of loop. now sum up the tracker
+    tracker.find_all(&true).count()
+}
+
+/// The guard's walk one step per frame, with the cells it has visited
+/// marked `X` like in the puzzle text.
+pub fn frames(mut grid: Grid<char>) -> impl Iterator<Item = Frame> {
+    let mut p = Some(get_start_point(&grid));
+    let mut dir = Direction::N;
+    let mut step = 0;
+    let mut visited = 0;
+    let mut seen = HashSet::new();
+    std::iter::from_fn(move || {
+        let current = p?;
+        if grid[current] != 'X' {
+            visited += 1;
+        }
+        grid[current] = 'X';
+        let mut shown = grid.clone();
+        shown[current] = arrow(dir);
+        let frame = Frame::new(shown)
+            .colour(grid.find_all(&'X'), Colour::Cyan)
+            .highlight([current], Colour::Yellow)
+            .with_caption(format!("step {}, {} cells visited", step, visited));
+
+        // same walk as solve, stopping when boxed in or back where it was
+        seen.insert((current, dir));
+        let mut turns = 0;
+        while grid.get(current + dir) == Some(&'#') && turns < 4 {
+            dir = dir.turn_right();
+            turns += 1;
+        }
+        let next_pos = current + dir;
+        p = (turns < 4 && grid.in_bounds(next_pos) && !seen.contains(&(next_pos, dir)))
+            .then_some(next_pos);
+        step += 1;
+        Some(frame)
+    })
+}
+
+fn arrow(dir: Direction) -> char {
+    match dir {
+        Direction::N => '^',
+        Direction::E => '>',
+        Direction::S => 'v',
+        _ => '<',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loops_and_boxed_in() {
+        // turning right at the top leads straight back into a wall
+        let grid = Part1::parse(".#.\n.^#\n...").unwrap();
+        assert_eq!(2, solve(&grid));
+        let grid = Part1::parse(".#.\n#^#\n.#.").unwrap();
+        assert_eq!(1, solve(&grid));
+        assert_eq!(1, frames(grid).count());
+        // a guard walking in circles
+        let grid = Part1::parse(".#..\n...#\n#^..\n..#.").unwrap();
+        assert_eq!(4, solve(&grid));
+        // the last frame is the guard back at the start
+        assert_eq!(5, frames(grid).count());
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = r"ab
+cd";
+        let answer = Grid::from_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+        let result = Grid::parse(input).unwrap();
+        assert_eq!(answer, result);
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = Grid::parse(include_str!("../data/examples/sample.txt").trim_end()).unwrap();
+        let frames: Vec<_> = frames(grid.clone()).collect();
+        assert_eq!(grid, frames[0].grid);
+        let last = frames.last().unwrap();
+        assert!(last
+            .caption
+            .ends_with(&format!("{} cells visited", solve(&grid))));
+        // the guard leaves through the bottom edge
+        assert_eq!(Some(Point::new(9, 7)), last.grid.find(&'v'));
+    }
+}
@@ -0,0 +1,157 @@
+use aoc_common::{
+    par::{self, Mode},
+    AocError, Direction, Grid, Point, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(input)?;
+        if grid.find(&'^').is_none() {
+            return Err(AocError::InvalidInput(
+                "the map has no guard '^'".to_string(),
+            ));
+        }
+        Ok(grid)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, Mode::current())
+    }
+}
+
+type Step = (Point, Direction);
+
+fn get_start_point(grid: &Grid<char>) -> Point {
+    grid.find(&'^').expect("bro where is the start point fr fr")
+}
+
+fn add_obstacle(grid: &Grid<char>, p: &Point) -> Grid<char> {
+    let mut new_grid = grid.clone();
+    new_grid[*p] = '#';
+    new_grid
+}
+
+fn solve(grid: &Grid<char>, mode: Mode) -> usize {
+    // first we need to find all the steps that we would traverse if we did not add any
+    let prev_steps = vec![(get_start_point(grid), Direction::N)];
+    let (normal_steps, _) = find_steps(grid, prev_steps);
+    let (start_point, _) = normal_steps[0];
+    let mut candidates = Vec::new();
+    let mut seen_points = HashSet::new();
+    // we will simulate adding an obstacle in each position in the path of the guard
+    // excluding his / her starting position
+    // we will also only try each point once, the first time the guard reaches it.
+    // hence the seen_points set.
+    for (i, (p, _)) in normal_steps.iter().enumerate().skip(1) {
+        //skipping start position because we can't place an obstacle there
+        // can't have obstacle on starting square. skip
+        if start_point == *p || !seen_points.insert(*p) {
+            continue;
+        }
+        candidates.push(i);
+    }
+
+    // each candidate is checked on its own copy of the grid
+    par::count_where(mode, &candidates, |&i| {
+        let new_grid = add_obstacle(grid, &normal_steps[i].0);
+        // we need to provide the previous steps before, but not including 'i'
+        let (_, loops) = find_steps(&new_grid, normal_steps[0..i].to_vec());
+        loops
+    })
+}
+fn find_steps(grid: &Grid<char>, mut prev_steps: Vec<Step>) -> (Vec<Step>, bool) {
+    // the returned bool is true if a loop was found. else it is false
+    let (mut p, mut dir) = prev_steps[prev_steps.len() - 1];
+    let mut seen = HashSet::new();
+    for s in prev_steps.iter() {
+        seen.insert(*s);
+    }
+    let mut turns = 0;
+    loop {
+        let next_pos = p + dir;
+        // turn one step at a time, the way after a turn can lead off the map
+        match grid.get(next_pos) {
+            None => break,
+            Some('#') if turns == 3 => {
+                // boxed in on all four sides, so the guard never leaves
+                return (prev_steps, true);
+            }
+            Some('#') => {
+                dir = dir.turn_right();
+                turns += 1;
+                continue;
+            }
+            Some(_) => turns = 0,
+        }
+        p = next_pos;
+        prev_steps.push((p, dir));
+        // check if it's in seen
+        if seen.contains(&(p, dir)) {
+            // found a loop!
+            return (prev_steps, true);
+        }
+        seen.insert(prev_steps[prev_steps.len() - 1]);
+        // add to prev and then add to hashset
+    }
+    (prev_steps, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen;
+
+    #[test]
+    fn test_parse() {
+        let input = r"ab
+cd";
+        let answer = Grid::from_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+        let result = Grid::parse(input).unwrap();
+        assert_eq!(answer, result);
+    }
+
+    /// Puts an obstruction on every free tile in turn and walks the guard
+    /// from the start until it leaves or repeats a position and heading.
+    fn naive_loops(grid: &Grid<char>) -> usize {
+        let start = get_start_point(grid);
+        grid.points()
+            .filter(|p| grid[*p] == '.')
+            .filter(|p| {
+                let grid = add_obstacle(grid, p);
+                let (mut p, mut dir) = (start, Direction::N);
+                let mut seen = HashSet::new();
+                while seen.insert((p, dir)) {
+                    match grid.get(p + dir) {
+                        None => return false,
+                        Some('#') => dir = dir.turn_right(),
+                        Some(_) => p += dir,
+                    }
+                }
+                true
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_naive_loops() {
+        let input = Part2::parse(include_str!("../data/examples/sample.txt")).unwrap();
+        assert_eq!(6, naive_loops(&input));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_matches_naive(seed: u64, size in 2..12usize) {
+            let grid = Part2::parse(&generate(gen::input, seed, size)).unwrap();
+            prop_assert_eq!(naive_loops(&grid), solve(&grid, Mode::Sequential));
+        }
+    }
+}
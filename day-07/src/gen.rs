@@ -0,0 +1,48 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// `size` calibration equations of two to six numbers below 1000.
+///
+/// Half of the test values come from putting random `+`, `*` and `||`
+/// operators between the numbers, so both parts find some true equations.
+/// Six three-digit numbers keep every result below 19 digits, which the
+/// solvers need to stay within `usize`.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let numbers: Vec<usize> = (0..rng.gen_range(2..=6))
+            .map(|_| rng.gen_range(1..1000))
+            .collect();
+        let target = if rng.gen_bool(0.5) {
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |acc, n| match rng.gen_range(0..3) {
+                    0 => acc + n,
+                    1 => acc * n,
+                    _ => format!("{}{}", acc, n).parse().unwrap(),
+                })
+        } else {
+            rng.gen_range(1..1_000_000)
+        };
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        out.push_str(&format!("{}: {}\n", target, numbers.join(" ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_concatenation_only_adds_equations() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 20);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            // every equation that is true with + and * is still true with ||
+            assert!(part1 <= Part2::solve(&Part2::parse(&text).unwrap()));
+        }
+    }
+}
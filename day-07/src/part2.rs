@@ -0,0 +1,142 @@
+use aoc_common::{
+    par::{self, Mode},
+    parse_complete, AocError, Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{newline, space1, u64},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Output = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, Mode::current())
+    }
+}
+
+fn solve(lines: &[(usize, Vec<usize>)], mode: Mode) -> u128 {
+    // every line's result can be near usize::MAX, so their sum needs more room
+    par::sum_by(mode, lines, |(res, operands)| {
+        solve_line(*res, operands) as u128
+    })
+}
+
+fn solve_line(res: usize, operands: &[usize]) -> usize {
+    if reachable(res, operands) {
+        res
+    } else {
+        0
+    }
+}
+
+/// Whether some choice of operators turns `operands` into `target`. Works
+/// back from the last operand, undoing each operator, so every value stays
+/// at most `target` and nothing can overflow.
+fn reachable(target: usize, operands: &[usize]) -> bool {
+    let Some((&last, rest)) = operands.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return target == last;
+    }
+    // whatever came before, multiplying it by zero gives zero
+    (last == 0 && target == 0)
+        || target
+            .checked_sub(last)
+            .is_some_and(|before| reachable(before, rest))
+        || (last != 0 && target.is_multiple_of(last) && reachable(target / last, rest))
+        || unconcat(target, last).is_some_and(|before| reachable(before, rest))
+}
+
+/// The `x` that `x || y` turns into `target`, if there is one.
+fn unconcat(target: usize, y: usize) -> Option<usize> {
+    let before = target.checked_sub(y)?;
+    let digits = y.checked_ilog10().map_or(1, |log| log + 1);
+    match 10usize.checked_pow(digits) {
+        Some(shift) => before.is_multiple_of(shift).then(|| before / shift),
+        // shifting anything but zero that far overflows
+        None => (before == 0).then_some(0),
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, (usize, Vec<usize>)> {
+    let (input, (res, operands)) =
+        separated_pair(u64, tag(": "), separated_list1(space1, u64))(input)?;
+    let res = res as usize;
+    let operands = operands.iter().map(|x| *x as usize).collect();
+    Ok((input, (res, operands)))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(usize, Vec<usize>)>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+
+    use super::*;
+    use crate::gen;
+
+    /// Tries every choice of operators from the left, in `u128` so nothing
+    /// the generated inputs make can overflow.
+    fn naive_reachable(target: usize, operands: &[usize]) -> bool {
+        let ops = operands.len() - 1;
+        (0..3usize.pow(ops as u32)).any(|mut choice| {
+            let mut value = operands[0] as u128;
+            for &y in &operands[1..] {
+                let y = y as u128;
+                value = match choice % 3 {
+                    0 => value + y,
+                    1 => value * y,
+                    _ => format!("{}{}", value, y).parse().unwrap(),
+                };
+                choice /= 3;
+            }
+            value == target as u128
+        })
+    }
+
+    #[test]
+    fn test_reachable_matches_naive() {
+        let mut lines = Part2::parse(&generate(gen::input, 7, 200)).unwrap();
+        lines.extend([
+            (0, vec![5, 0]),
+            (50, vec![5, 0]),
+            (5, vec![0, 5]),
+            (105, vec![1, 0, 5]),
+            (6, vec![2, 3, 0, 6]),
+        ]);
+        for (target, operands) in &lines {
+            assert_eq!(
+                naive_reachable(*target, operands),
+                reachable(*target, operands),
+                "{}: {:?}",
+                target,
+                operands
+            );
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        // multiplying by zero brings back a value that overflowed
+        let input =
+            Part2::parse("7: 18446744073709551615 9 0 7\n9: 18446744073709551615 9 9").unwrap();
+        assert_eq!(7, Part2::solve(&input));
+        let max = "18446744073709551615: 18446744073709551615";
+        let input = Part2::parse(&format!("{}\n{}", max, max)).unwrap();
+        assert_eq!(2 * u128::from(u64::MAX), Part2::solve(&input));
+    }
+}
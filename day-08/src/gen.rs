@@ -0,0 +1,40 @@
+use aoc_common::gen::{GenRng, Rng, SliceRandom};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` by `size` map with a few frequencies of two to four antennas
+/// each. No two antennas share a tile.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let size = size.max(2);
+    let mut cells = vec![b'.'; size * size];
+    let mut tiles: Vec<usize> = (0..cells.len()).collect();
+    tiles.shuffle(rng);
+    let mut tiles = tiles.into_iter();
+    let count = rng.gen_range(1..=(size / 2).clamp(1, FREQUENCIES.len()));
+    for frequency in FREQUENCIES.choose_multiple(rng, count) {
+        for tile in tiles.by_ref().take(rng.gen_range(2..=4)) {
+            cells[tile] = *frequency;
+        }
+    }
+    cells
+        .chunks(size)
+        .map(|row| format!("{}\n", String::from_utf8_lossy(row)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_harmonics_only_add_antinodes() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 12);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            assert!(part1 <= Part2::solve(&Part2::parse(&text).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_common::{AocError, Grid, Point, Solution};
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+fn solve(grid: &Grid<char>) -> usize {
+    let data = make_hashmap(grid);
+    let mut antinodes = HashSet::new();
+    for (_, points) in data.iter() {
+        // we need to iterate through all the pairs in the set
+        // if the num of elements in it is greater than 2
+        if points.len() < 2 {
+            continue;
+        }
+        // get the 2-combos of the points
+        for combo in points.iter().combinations(2) {
+            let diff = *combo[1] - *combo[0];
+            // go in the dir away from diff
+            let mut step = 0;
+            let mut anti1 = *combo[0] - diff * step;
+            while grid.in_bounds(anti1) {
+                // we're in the grid. add to antinodes
+                antinodes.insert(anti1);
+                step += 1;
+                anti1 = *combo[0] - diff * step;
+            }
+
+            let mut step = 0;
+            let mut anti2 = *combo[1] + diff * step;
+            while grid.in_bounds(anti2) {
+                // we're in the grid. add to antinodes
+                antinodes.insert(anti2);
+                step += 1;
+                anti2 = *combo[1] + diff * step;
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+type DatMap = HashMap<char, HashSet<Point>>;
+fn make_hashmap(grid: &Grid<char>) -> DatMap {
+    let mut hm: HashMap<char, HashSet<Point>> = HashMap::new();
+    for (p, cell) in grid.iter() {
+        if !cell.is_alphanumeric() {
+            continue;
+        }
+        hm.entry(*cell).or_default().insert(p);
+    }
+    hm
+}
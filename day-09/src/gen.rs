@@ -0,0 +1,31 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// A disk map for `size` files: file lengths of one to nine blocks, with
+/// gaps of zero to nine blocks between them and none after the last.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut out = String::new();
+    for i in 0..size.max(1) {
+        if i > 0 {
+            out.push(char::from(b'0' + rng.gen_range(0..10)));
+        }
+        out.push(char::from(b'0' + rng.gen_range(1..10)));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+
+    use super::*;
+
+    #[test]
+    fn test_no_empty_files() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 30);
+            assert_eq!(59, text.trim_end().len());
+            assert!(text.trim_end().chars().step_by(2).all(|c| c != '0'));
+        }
+    }
+}
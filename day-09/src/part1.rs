@@ -0,0 +1,122 @@
+use aoc_common::{AocError, Solution};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Vec<Option<usize>>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input.clone())
+    }
+}
+
+fn solve(mut data: Vec<Option<usize>>) -> usize {
+    // we will need a double pointer and to swap things around
+    let mut file_index = 0;
+    let mut blank_index = data.len() - 1;
+
+    while file_index < blank_index {
+        if data[file_index].is_some() {
+            // occupado
+            file_index += 1;
+            continue;
+        } else {
+            // aha! a free space
+            if data[blank_index].is_some() {
+                // swap em like it's hot
+                data.swap(file_index, blank_index);
+            } else {
+                // nothing to swap in here.
+                blank_index -= 1;
+                continue;
+            }
+        }
+    }
+    data.iter()
+        .filter_map(|e| *e)
+        .enumerate()
+        .map(|(i, x)| x * i)
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Option<usize>>, AocError> {
+    let nums = input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| AocError::parse_at(input, &input[i..], "a digit"))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    // every other digit is a file length, starting with the first
+    if !nums.iter().step_by(2).any(|&len| len > 0) {
+        return Err(AocError::InvalidInput(
+            "the disk map has no files".to_string(),
+        ));
+    }
+
+    let vec_size = nums.iter().sum();
+    let mut data: Vec<Option<usize>> = vec![None; vec_size];
+    let mut data_index = 0;
+    for (id, c) in nums.chunks(2).enumerate() {
+        let file_len = c[0];
+        let blank_len = c.get(1).unwrap_or(&0);
+        data.iter_mut()
+            .skip(data_index)
+            .take(file_len)
+            .for_each(|x| *x = Some(id));
+        data_index = data_index + file_len + blank_len
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_no_files() {
+        for input in ["", "\n", "0", "00", "05"] {
+            let err = Part1::parse(input).unwrap_err();
+            assert!(matches!(err, AocError::InvalidInput(_)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parsing() {
+        let input = "2333133121414131402";
+        let data = parse_input(input).unwrap();
+        let result_chars = "00...111...2...333.44.5555.6666.777.888899";
+        let result_chars: Vec<Option<usize>> = result_chars
+            .chars()
+            .map(|c| {
+                if c.is_numeric() {
+                    Some(c.to_digit(10).unwrap() as usize)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        assert_eq!(result_chars, data);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("23x1\n").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            }
+        ));
+    }
+}
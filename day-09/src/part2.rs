@@ -0,0 +1,183 @@
+use aoc_common::{AocError, Solution};
+use std::collections::HashMap;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = (Files, Blanks);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let (files, blanks) = input.clone();
+        solve(files, blanks)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pos: usize,
+    size: usize,
+}
+
+impl Chunk {
+    fn new(pos: usize, size: usize) -> Self {
+        Chunk { pos, size }
+    }
+}
+
+type Files = HashMap<usize, Chunk>;
+type Blanks = Vec<Chunk>;
+fn parse_input(input: &str) -> Result<(Files, Blanks), AocError> {
+    let nums = input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| AocError::parse_at(input, &input[i..], "a digit"))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    // every other digit is a file length, starting with the first
+    if !nums.iter().step_by(2).any(|&len| len > 0) {
+        return Err(AocError::InvalidInput(
+            "the disk map has no files".to_string(),
+        ));
+    }
+    let mut files: Files = HashMap::new();
+    let mut blanks: Blanks = vec![];
+    let mut pos = 0;
+    for (id, w) in nums.chunks(2).enumerate() {
+        let file = Chunk::new(pos, w[0]);
+        files.insert(id, file);
+        pos += w[0];
+        let blank_len = w.get(1).unwrap_or(&0);
+        let blank = Chunk::new(pos, *blank_len);
+        blanks.push(blank);
+        pos += blank_len;
+    }
+
+    Ok((files, blanks))
+}
+
+fn solve(mut files: Files, mut blanks: Blanks) -> usize {
+    // we start from the highest id and keep going down
+
+    let max_id = *files.keys().max().unwrap();
+    for id in (0..=max_id).rev() {
+        let file = files.get_mut(&id).unwrap();
+        // find a blank which would fit
+        if let Some(blank_index) = blanks.iter().position(|b| b.size >= file.size) {
+            // yay we found a blank to fill!
+            let blank = blanks.get_mut(blank_index).unwrap();
+            // we need to check if the blank we're looking for is to the right of the file
+            // if it is. we've compressed all we can
+            if blank.pos >= file.pos {
+                continue;
+            }
+            if blank.size == file.size {
+                // move the file to the location of the blank
+                file.pos = blank.pos;
+                // we delete the blank
+                blanks.remove(blank_index);
+            } else {
+                // there's some space left
+                file.pos = blank.pos;
+                blank.pos = file.pos + file.size;
+                blank.size -= file.size;
+            }
+        }
+    }
+    let mut sum = 0;
+    for (id, file) in files {
+        for delta in 0..file.size {
+            sum += id * (file.pos + delta)
+        }
+    }
+    sum
+} // fn parse_input(input: &str) -> Vec<Option<usize>> {
+  //     let nums: Vec<usize> = input
+  //         .chars()
+  //         .map(|c| c.to_digit(10).unwrap() as usize)
+  //         .collect();
+
+//     let vec_size = nums.iter().sum();
+//     let mut data: Vec<Option<usize>> = vec![None; vec_size];
+//     let mut data_index = 0;
+//     for (id, c) in nums.chunks(2).enumerate() {
+//         let file_len = c[0];
+//         let blank_len = c.get(1).unwrap_or(&0);
+//         data.iter_mut()
+//             .skip(data_index)
+//             .take(file_len)
+//             .for_each(|x| *x = Some(id));
+//         data_index = data_index + file_len + blank_len
+//     }
+//     data
+// }
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen;
+
+    /// Lays the disk out block by block and moves each file into the first
+    /// run of free blocks before it that is long enough.
+    fn naive_checksum(map: &str) -> usize {
+        let mut disk: Vec<Option<usize>> = vec![];
+        for (i, c) in map.trim_end().chars().enumerate() {
+            let len = c.to_digit(10).unwrap() as usize;
+            let block = (i % 2 == 0).then_some(i / 2);
+            disk.extend(std::iter::repeat_n(block, len));
+        }
+        let max_id = disk.iter().flatten().max().copied().unwrap_or(0);
+        for id in (0..=max_id).rev() {
+            let start = disk.iter().position(|b| *b == Some(id)).unwrap();
+            let len = disk[start..].iter().take_while(|b| **b == Some(id)).count();
+            let mut run = 0;
+            for i in 0..start {
+                run = if disk[i].is_none() { run + 1 } else { 0 };
+                if run == len {
+                    let to = i + 1 - len;
+                    for k in 0..len {
+                        disk[to + k] = Some(id);
+                        disk[start + k] = None;
+                    }
+                    break;
+                }
+            }
+        }
+        disk.iter()
+            .enumerate()
+            .map(|(i, block)| block.map_or(0, |id| i * id))
+            .sum()
+    }
+
+    #[test]
+    fn test_parse_no_files() {
+        for input in ["", "\n", "0", "00", "05"] {
+            let err = Part2::parse(input).unwrap_err();
+            assert!(matches!(err, AocError::InvalidInput(_)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_naive_checksum() {
+        assert_eq!(2858, naive_checksum("2333133121414131402"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_matches_naive(seed: u64, size in 1..40usize) {
+            let map = generate(gen::input, seed, size);
+            let (files, blanks) = Part2::parse(&map).unwrap();
+            prop_assert_eq!(naive_checksum(&map), solve(files, blanks));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::{
+    gen::{GenRng, Rng, SliceRandom},
+    Grid, Point,
+};
+
+/// A `size` by `size` topographic map. Random heights alone hardly ever
+/// line up into a trail, so a few hiking trails from 0 to 9 are walked
+/// over the noise.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let size = size.max(2);
+    let heights = (0..size * size).map(|_| rng.gen_range(0..10)).collect();
+    let mut grid = Grid::new(size, size, heights);
+    for _ in 0..size / 2 + 1 {
+        let mut p = Point::new(
+            rng.gen_range(0..size) as isize,
+            rng.gen_range(0..size) as isize,
+        );
+        for height in 0..10 {
+            grid[p] = height;
+            let next: Vec<Point> = grid.neighbours4(p).collect();
+            p = *next.choose(rng).expect("a 2x2 grid has neighbours");
+        }
+    }
+    let mut out = grid
+        .map(|h: &u32| char::from_digit(*h, 10).unwrap())
+        .to_string();
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_every_summit_has_a_trail() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 12);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            // every reachable summit has at least one distinct trail
+            assert!(part1 <= Part2::solve(&Part2::parse(&text).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_common::{
+    par::{self, Mode},
+    AocError, Grid, Point, Solution,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Grid<usize>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_grid(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, Mode::current())
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Grid<usize>, AocError> {
+    Grid::try_parse_with(input, "a height 0-9 or '.'", |c| match c {
+        '.' => Some(IMPASSABLE),
+        _ => c.to_digit(10).map(|d| d as usize),
+    })
+}
+
+/// Stands in for '.' tiles, too high to ever be the next step of a trail.
+const IMPASSABLE: usize = 100;
+
+fn get_start_points(grid: &Grid<usize>) -> Vec<Point> {
+    grid.find_all(&0).collect()
+}
+
+fn get_trailhead_score(grid: &Grid<usize>, start_point: Point) -> usize {
+    let mut score = 0;
+    let mut queue = vec![start_point];
+    while let Some(point) = queue.pop() {
+        if grid[point] == 9 {
+            score += 1;
+            continue;
+        }
+        for next_point in grid.neighbours4(point) {
+            // check if value is 1 more than the current point value
+            if grid[next_point] == grid[point] + 1 {
+                // great! add to the queue
+                queue.push(next_point);
+            }
+        }
+    }
+    score
+}
+
+fn solve(grid: &Grid<usize>, mode: Mode) -> usize {
+    let start_points = get_start_points(grid);
+    par::sum_by(mode, &start_points, |sp| get_trailhead_score(grid, *sp))
+}
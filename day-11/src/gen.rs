@@ -0,0 +1,26 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// One line of `size` stones engraved with numbers below 100000.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.gen_range(0..100_000u32).to_string())
+        .collect();
+    format!("{}\n", stones.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_more_blinks_only_add_stones() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 4);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            assert!(part1 <= Part2::solve(&Part2::parse(&text).unwrap()));
+        }
+    }
+}
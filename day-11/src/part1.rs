@@ -0,0 +1,95 @@
+use aoc_common::{parse_complete, AocError, Solution};
+
+use nom::{
+    character::complete::{space1, u64},
+    multi::separated_list1,
+    IResult,
+};
+const ITERATIONS: usize = 25;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Vec<Stone>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input.clone())
+    }
+}
+
+/// Wide enough that no stone overflows: a stone with an odd number of digits
+/// gains at most 7 more over two multiplications before it splits in half.
+type Stone = u128;
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Stone>> {
+    let (input, data) = separated_list1(space1, u64)(input)?;
+    let data = data.iter().map(|x| Stone::from(*x)).collect();
+    Ok((input, data))
+}
+
+fn get_num_digits(num: Stone) -> usize {
+    if num == 0 {
+        1
+    } else {
+        let mut len = 0;
+        let mut i = num;
+        while i > 0 {
+            i /= 10;
+            len += 1;
+        }
+        len
+    }
+}
+
+fn split_even_digits(num: Stone) -> (Stone, Stone) {
+    let num_digits = get_num_digits(num);
+    assert_eq!(0, num_digits % 2);
+
+    let second_part = num % (Stone::pow(10, num_digits as u32 / 2));
+
+    let first_part = num / (Stone::pow(10, num_digits as u32 / 2));
+
+    (first_part, second_part)
+}
+
+fn solve(mut data: Vec<Stone>) -> usize {
+    for _ in 0..ITERATIONS {
+        let mut new_vec = Vec::with_capacity(data.len() * 2);
+        for e in data.iter() {
+            if *e == 0 {
+                new_vec.push(1);
+            } else if get_num_digits(*e).is_multiple_of(2) {
+                let (f, s) = split_even_digits(*e);
+                new_vec.push(f);
+                new_vec.push(s);
+            } else {
+                new_vec.push(e * 2024)
+            }
+        }
+        data = new_vec;
+    }
+
+    data.len()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_split_even_digits() {
+        let data = [24, 5678, 890689, 98765438];
+        let ans = [(2, 4), (56, 78), (890, 689), (9876, 5438)];
+        for (i, (f, s)) in data.iter().zip(ans.iter()) {
+            let (first, second) = split_even_digits(*i);
+            assert_eq!(first, *f);
+            assert_eq!(second, *s);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_common::{parse_complete, AocError, Solution};
+use std::collections::HashMap;
+
+use nom::{
+    character::complete::{space1, u64},
+    multi::separated_list1,
+    IResult,
+};
+const ITERATIONS: usize = 75;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<Stone>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, ITERATIONS)
+    }
+}
+
+/// Wide enough that no stone overflows: a stone with an odd number of digits
+/// gains at most 7 more over two multiplications before it splits in half.
+type Stone = u128;
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Stone>> {
+    let (input, data) = separated_list1(space1, u64)(input)?;
+    let data = data.iter().map(|x| Stone::from(*x)).collect();
+    Ok((input, data))
+}
+
+fn get_num_digits(num: Stone) -> usize {
+    if num == 0 {
+        1
+    } else {
+        let mut len = 0;
+        let mut i = num;
+        while i > 0 {
+            i /= 10;
+            len += 1;
+        }
+        len
+    }
+}
+
+fn split_even_digits(num: Stone) -> (Stone, Stone) {
+    let num_digits = get_num_digits(num);
+    assert_eq!(0, num_digits % 2);
+
+    let second_part = num % (Stone::pow(10, num_digits as u32 / 2));
+
+    let first_part = num / (Stone::pow(10, num_digits as u32 / 2));
+
+    (first_part, second_part)
+}
+
+fn calc_output_size_for_num(
+    num: Stone,
+    iterations: usize,
+    cache: &mut HashMap<(Stone, usize), usize>,
+) -> usize {
+    if iterations == 0 {
+        return 1;
+    }
+    // check if we've seen the result for this one before
+    if let Some(x) = cache.get(&(num, iterations)) {
+        return *x;
+    }
+    let ans = if num == 0 {
+        calc_output_size_for_num(1, iterations - 1, cache)
+    } else if get_num_digits(num).is_multiple_of(2) {
+        let (f, s) = split_even_digits(num);
+        calc_output_size_for_num(f, iterations - 1, cache)
+            + calc_output_size_for_num(s, iterations - 1, cache)
+    } else {
+        calc_output_size_for_num(num * 2024, iterations - 1, cache)
+    };
+
+    cache.insert((num, iterations), ans);
+    ans
+}
+
+fn solve(data: &[Stone], iterations: usize) -> usize {
+    // make a hashmap as a cache
+    let mut cache: HashMap<(Stone, usize), usize> = HashMap::new();
+    // solve each num independently
+    data.iter()
+        .map(|x| calc_output_size_for_num(*x, iterations, &mut cache))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen;
+
+    /// Blinks the actual row of stones, which is only feasible for a few
+    /// blinks.
+    fn naive_blink(stones: &[Stone], blinks: usize) -> usize {
+        let mut stones = stones.to_vec();
+        for _ in 0..blinks {
+            stones = stones
+                .iter()
+                .flat_map(|stone| {
+                    let digits = stone.to_string();
+                    if *stone == 0 {
+                        vec![1]
+                    } else if digits.len() % 2 == 0 {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+        stones.len()
+    }
+
+    #[test]
+    fn test_big_stones() {
+        let stones = Part2::parse("9999999999999999999 18446744073709551615").unwrap();
+        assert_eq!(naive_blink(&stones, 12), solve(&stones, 12));
+        // far too many to blink one by one, but none of them overflow
+        assert!(Part2::solve(&stones) > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_matches_naive(seed: u64, size in 1..5usize, blinks in 0..16usize) {
+            let stones = Part2::parse(&generate(gen::input, seed, size)).unwrap();
+            prop_assert_eq!(naive_blink(&stones, blinks), solve(&stones, blinks));
+        }
+    }
+
+    #[test]
+    fn test_split_even_digits() {
+        let data = [24, 5678, 890689, 98765438];
+        let ans = [(2, 4), (56, 78), (890, 689), (9876, 5438)];
+        for (i, (f, s)) in data.iter().zip(ans.iter()) {
+            let (first, second) = split_even_digits(*i);
+            assert_eq!(first, *f);
+            assert_eq!(second, *s);
+        }
+    }
+
+    #[test]
+    fn test_1_blink() {
+        let input = r"125 17";
+        let (_, data) = parse_input(input).unwrap();
+        let known_iter_and_answers = [
+            (0, 2),
+            (1, 3),
+            (2, 4),
+            (3, 5),
+            (4, 9),
+            (5, 13),
+            (6, 22),
+            (25, 55312),
+        ];
+        for (iters, ans) in known_iter_and_answers.iter() {
+            assert_eq!(*ans, solve(&data, *iters));
+        }
+    }
+}
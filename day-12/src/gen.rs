@@ -0,0 +1,42 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// A `size` by `size` garden of up to eight plant types. Most plots take
+/// the plant of the plot to their left or above, so the regions come out
+/// in all sorts of shapes rather than single plots.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut rows: Vec<Vec<char>> = vec![];
+    for r in 0..size {
+        let mut row: Vec<char> = vec![];
+        for c in 0..size {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if c > 0 => row[c - 1],
+                4..=6 if r > 0 => rows[r - 1][c],
+                _ => char::from(b'A' + rng.gen_range(0..8)),
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+    rows.iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{gen::generate, Solution};
+
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+
+    #[test]
+    fn test_sides_never_outnumber_fences() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 12);
+            let part1 = Part1::solve(&Part1::parse(&text).unwrap());
+            // a region never has more sides than fence segments
+            assert!(Part2::solve(&Part2::parse(&text).unwrap()) <= part1);
+        }
+    }
+}
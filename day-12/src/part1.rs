@@ -0,0 +1,71 @@
+use aoc_common::{AocError, Direction, Grid, Point, Solution};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+fn solve<T: PartialEq>(grid: &Grid<T>) -> usize {
+    let mut patches = vec![];
+    let mut seen = HashSet::new();
+    for p in grid.points() {
+        if !seen.contains(&p) {
+            let patch = flood_fill(grid, &seen, &p);
+            seen.extend(patch.iter());
+            patches.push(patch);
+        }
+    }
+
+    // we have the patches. now we need to score them
+    let mut score = 0;
+    for patch in patches.iter() {
+        let area = patch.len();
+        // getting the perimeter is more involved
+        let mut perimeter = 0;
+        for p in patch {
+            // see how many of its neighbors are in this object
+            // or we could check the grid as well
+            for dir in Direction::CARDINAL {
+                let n = *p + dir;
+                if !patch.contains(&n) {
+                    perimeter += 1;
+                }
+            }
+        }
+
+        score += area * perimeter;
+    }
+    score
+}
+
+fn flood_fill<T: PartialEq>(
+    grid: &Grid<T>,
+    seen: &HashSet<Point>,
+    start_point: &Point,
+) -> HashSet<Point> {
+    let value = &grid[*start_point];
+    let mut members = HashSet::from([*start_point]);
+    let mut queue = VecDeque::from([*start_point]);
+    while let Some(p) = queue.pop_front() {
+        for neighbor in grid.neighbours4(p) {
+            if !seen.contains(&neighbor) && !members.contains(&neighbor) && *value == grid[neighbor]
+            {
+                members.insert(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    members
+}
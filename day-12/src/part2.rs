@@ -0,0 +1,168 @@
+use aoc_common::{AocError, Direction, Grid, Point, Solution};
+use core::panic;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Debug,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input)
+    }
+}
+
+// the side of a cell that an edge is on
+type Dir = Direction;
+
+fn solve<T: PartialEq + Debug>(grid: &Grid<T>) -> usize {
+    let mut patches = vec![];
+    let mut seen = HashSet::new();
+    for p in grid.points() {
+        if !seen.contains(&p) {
+            let patch = flood_fill(grid, &seen, &p);
+            seen.extend(patch.iter());
+            patches.push(patch);
+        }
+    }
+
+    // we have the patches. now for each patch we need to calculate the number of sides it has
+
+    let mut score = 0;
+    for patch in patches.iter() {
+        let area = patch.len();
+        // getting the num of sides is more involved
+        let num_sides = count_sides(grid, patch);
+
+        score += area * num_sides;
+    }
+    score
+}
+
+fn flood_fill<T: PartialEq>(
+    grid: &Grid<T>,
+    seen: &HashSet<Point>,
+    start_point: &Point,
+) -> HashSet<Point> {
+    let value = &grid[*start_point];
+    let mut members = HashSet::from([*start_point]);
+    let mut queue = VecDeque::from([*start_point]);
+    while let Some(p) = queue.pop_front() {
+        for neighbor in grid.neighbours4(p) {
+            if !seen.contains(&neighbor) && !members.contains(&neighbor) && *value == grid[neighbor]
+            {
+                members.insert(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    members
+}
+
+fn get_point_edges<T: PartialEq>(grid: &Grid<T>, p: Point) -> HashSet<Dir> {
+    let mut edges = HashSet::new();
+    let value = &grid[p];
+    for dir in Direction::CARDINAL.iter() {
+        let n = p + *dir;
+        if grid.get(n).is_some_and(|other| value != other) {
+            // don't matche so is an edge
+            edges.insert(*dir);
+        }
+
+        // or if it's out of bounds it's a boundary
+        if !grid.in_bounds(n) {
+            edges.insert(*dir);
+        }
+    }
+    edges
+}
+
+type PeMap = HashMap<Point, HashSet<Dir>>;
+
+fn flood_fill_point_edges<T: PartialEq>(
+    grid: &Grid<T>,
+    pe_map: &PeMap,
+    p: Point,
+    e: Dir,
+) -> HashSet<(Point, Dir)> {
+    let directions = match e {
+        // if edge pointing up or down. explore left right
+        Direction::N | Direction::S => [Direction::W, Direction::E],
+        // if edge pointing left right explore up down
+        Direction::E | Direction::W => [Direction::N, Direction::S],
+        _ => panic!("unknown direction"),
+    };
+
+    let mut seen_points = HashSet::from([p]);
+    let mut queue = VecDeque::from([p]);
+    let mut point_edges: HashSet<(Point, Dir)> = HashSet::from([(p, e)]);
+
+    while let Some(p) = queue.pop_front() {
+        for dir in directions.iter() {
+            let neighbor = p + *dir;
+            if grid.in_bounds(neighbor) && !seen_points.contains(&neighbor) {
+                // not explored this point before.
+                // get the edges of the neighbor
+                if let Some(neighbor_edges) = pe_map.get(&neighbor) {
+                    // if the neighbor is in the pe_map we can now look for the set of it's edges
+                    if neighbor_edges.contains(&e) {
+                        // aha we share an edge.
+                        // add this to point_edges
+                        point_edges.insert((neighbor, e));
+                        queue.push_back(neighbor);
+                    }
+                }
+                seen_points.insert(neighbor);
+            }
+        }
+    }
+    point_edges
+}
+
+fn get_patch_point_edge_map<T: PartialEq>(grid: &Grid<T>, patch: &HashSet<Point>) -> PeMap {
+    // returns a set of tuples.
+    // first element is the coordinate of the element with the edge.
+    // second element is the direction (up down left right) that the edge is facing
+    let mut point_edges: PeMap = HashMap::new();
+    for p in patch.iter() {
+        let edges = get_point_edges(grid, *p);
+        point_edges.insert(*p, edges);
+    }
+
+    point_edges
+}
+
+fn count_sides<T: PartialEq>(grid: &Grid<T>, patch: &HashSet<Point>) -> usize {
+    let mut count = 0;
+    // get all the point edges
+    let point_edges_map = get_patch_point_edge_map(grid, patch);
+
+    let mut seen: HashSet<(Point, Dir)> = HashSet::new();
+    for (p, edges) in point_edges_map.iter() {
+        for edge in edges.iter() {
+            if seen.contains(&(*p, *edge)) {
+                continue;
+            }
+
+            // found a point edge we have not seen before
+            count += 1;
+            // find all contiguous point edges
+            for pe in flood_fill_point_edges(grid, &point_edges_map, *p, *edge).iter() {
+                seen.insert(*pe);
+            }
+            //insert this pe into seen itself
+            seen.insert((*p, *edge));
+        }
+    }
+
+    count
+}
@@ -0,0 +1,34 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// `size` claw machines with buttons that move the claw 10 to 99 along
+/// each axis.
+///
+/// About one machine in ten has buttons that move the claw the same way,
+/// which leaves a whole line of ways to reach the prize. Half of the prizes
+/// can be reached with at most 100 presses of each button.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut machines = vec![];
+    for _ in 0..size.max(1) {
+        let (a, b): ((i64, i64), (i64, i64)) = if rng.gen_bool(0.1) {
+            let step: (i64, i64) = (rng.gen_range(2..10), rng.gen_range(2..10));
+            let (n, m): (i64, i64) = (rng.gen_range(5..10), rng.gen_range(5..10));
+            ((step.0 * n, step.1 * n), (step.0 * m, step.1 * m))
+        } else {
+            (
+                (rng.gen_range(10..100), rng.gen_range(10..100)),
+                (rng.gen_range(10..100), rng.gen_range(10..100)),
+            )
+        };
+        let prize = if rng.gen_bool(0.5) {
+            let (n, m) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            (a.0 * n + b.0 * m, a.1 * n + b.1 * m)
+        } else {
+            (rng.gen_range(1000..20000), rng.gen_range(1000..20000))
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
@@ -0,0 +1,6 @@
+pub mod gen;
+pub mod machine;
+pub mod part1;
+pub mod part2;
+
+aoc_common::example_tests!();
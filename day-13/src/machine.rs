@@ -0,0 +1,289 @@
+//! Claw machines and the cheapest way to win their prizes, shared by both
+//! parts.
+
+use aoc_common::par::{self, Mode};
+
+use nom::{
+    bytes::complete::{tag, take_till},
+    character::{
+        complete::{i128, newline},
+        is_digit, is_newline,
+    },
+    combinator::verify,
+    multi::separated_list1,
+    IResult,
+};
+
+/// The furthest a button or prize can be along an axis. Anything further
+/// could overflow the products in [`solve_machine`], even before part 2's
+/// offset is added.
+pub const MAX_COORDINATE: i128 = i64::MAX as i128;
+
+/// How far buttons A and B move the claw, and where the prize is.
+pub type Machine = ((i128, i128), (i128, i128), (i128, i128));
+
+/// The tokens it takes to win every prize that can be won.
+pub fn total_cost(machines: &[Machine], mode: Mode) -> i128 {
+    par::sum_by(mode, machines, |machine| {
+        solve_machine(machine).map_or(0, |presses| calculate_cost(presses.0, presses.1))
+    })
+}
+
+/// The cheapest number of times to press A and B to land on the prize, if
+/// it can be done.
+pub fn solve_machine(machine: &Machine) -> Option<(i128, i128)> {
+    // let's write some equations
+    let ((ax, ay), (bx, by), (px, py)) = *machine;
+
+    let det = ax * by - ay * bx;
+    if det == 0 {
+        return solve_collinear(machine);
+    }
+    // Cramer's rule
+    let (a, b) = (px * by - py * bx, ax * py - ay * px);
+    if a % det != 0 || b % det != 0 {
+        // no int solution
+        return None;
+    }
+    let (a, b) = (a / det, b / det);
+    // the exact solution can need a negative number of presses
+    (a >= 0 && b >= 0).then_some((a, b))
+}
+
+/// Solves a machine whose buttons move the claw along the same line, or
+/// not at all. The prize has to be on that line too, and then only the
+/// distance along it matters, which one axis measures as long as some
+/// button moves along it.
+fn solve_collinear(machine: &Machine) -> Option<(i128, i128)> {
+    let ((ax, ay), (bx, by), (px, py)) = *machine;
+    if ax * py != ay * px || bx * py != by * px {
+        return None;
+    }
+    if ax == 0 && bx == 0 && ay == 0 && by == 0 {
+        return (px == 0 && py == 0).then_some((0, 0));
+    }
+    if ax != 0 || bx != 0 {
+        cheapest_on_line(ax, bx, px)
+    } else {
+        cheapest_on_line(ay, by, py)
+    }
+}
+
+/// The cheapest `(a, b)` with `a * u + b * v == w`, where none of `u`, `v`
+/// and `w` is negative, as the input has no signs.
+fn cheapest_on_line(u: i128, v: i128, w: i128) -> Option<(i128, i128)> {
+    match (u, v) {
+        (0, 0) => (w == 0).then_some((0, 0)),
+        (0, _) => (w % v == 0).then_some((0, w / v)),
+        (_, 0) => (w % u == 0).then_some((w / u, 0)),
+        _ => {
+            let (g, x, _) = extended_gcd(u, v);
+            if w % g != 0 {
+                return None;
+            }
+            let (u, v, w) = (u / g, v / g, w / g);
+            // u * x == 1 (mod v), so this is the fewest A presses that
+            // leave a multiple of v for B
+            let a = x.rem_euclid(v) * (w % v) % v;
+            if a * u > w {
+                return None;
+            }
+            let b = (w - a * u) / v;
+            // every other solution swaps u presses of B for v more of A,
+            // which only saves tokens when 3 * v < u
+            if 3 * v < u {
+                let swaps = b / u;
+                Some((a + swaps * v, b - swaps * u))
+            } else {
+                Some((a, b))
+            }
+        }
+    }
+}
+
+/// `(g, x, y)` where `g` is the greatest common divisor of `a` and `b`
+/// and `a * x + b * y == g`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+pub fn calculate_cost(a: i128, b: i128) -> i128 {
+    a * 3 + b
+}
+
+fn parse_line(input: &str) -> IResult<&str, (i128, i128)> {
+    // let (input, _) =
+    let (input, _) = take_till(|c: char| is_digit(c as u8))(input)?;
+    let (input, num_1) = coordinate(input)?;
+    let (input, _) = take_till(|c: char| is_digit(c as u8))(input)?;
+    let (input, num_2) = coordinate(input)?;
+    let (input, _) = take_till(|c: char| is_newline(c as u8))(input)?;
+
+    Ok((input, (num_1, num_2)))
+}
+
+fn coordinate(input: &str) -> IResult<&str, i128> {
+    verify(i128, |&n| n <= MAX_COORDINATE)(input)
+}
+
+fn parse_block(input: &str) -> IResult<&str, Machine> {
+    let (input, a) = parse_line(input)?;
+    let (input, _) = newline(input)?;
+    let (input, b) = parse_line(input)?;
+    let (input, _) = newline(input)?;
+    let (input, p) = parse_line(input)?;
+    Ok((input, (a, b, p)))
+}
+
+pub fn parse_input(input: &str) -> IResult<&str, Vec<Machine>> {
+    separated_list1(tag("\n\n"), parse_block)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen;
+
+    #[test]
+    fn test_parse_input() {
+        let input = r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176";
+
+        let (_, machines) = parse_input(input).unwrap();
+        let ((ax, ay), (bx, by), (px, py)) = machines[0];
+
+        assert_eq!(ax, 94);
+        assert_eq!(ay, 34);
+        assert_eq!(bx, 22);
+        assert_eq!(by, 67);
+        assert_eq!(px, 8400);
+        assert_eq!(py, 5400);
+
+        let ((ax, ay), (bx, by), (px, py)) = machines[1];
+
+        assert_eq!(ax, 26);
+        assert_eq!(ay, 66);
+        assert_eq!(bx, 67);
+        assert_eq!(by, 21);
+        assert_eq!(px, 12748);
+        assert_eq!(py, 12176);
+    }
+
+    #[test]
+    fn test_parse_block() {
+        let input = r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400";
+
+        let (_, ((ax, ay), (bx, by), (px, py))) = parse_block(input).unwrap();
+        assert_eq!(ax, 94);
+        assert_eq!(ay, 34);
+        assert_eq!(bx, 22);
+        assert_eq!(by, 67);
+        assert_eq!(px, 8400);
+        assert_eq!(py, 5400);
+    }
+
+    #[test]
+    fn test_parse_big_numbers() {
+        let input = "Button A: X+5000000000, Y+1
+Button B: X+1, Y+9223372036854775806
+Prize: X=5000000001, Y=9223372036854775807";
+        let (_, machines) = parse_input(input).unwrap();
+        assert_eq!(
+            vec![(
+                (5000000000, 1),
+                (1, MAX_COORDINATE - 1),
+                (5000000001, MAX_COORDINATE)
+            )],
+            machines
+        );
+        assert_eq!(Some((1, 1)), solve_machine(&machines[0]));
+        let input = input.replace("9223372036854775807", "9223372036854775808");
+        assert!(parse_input(&input).is_err());
+    }
+
+    /// Tries every number of A presses that keeps the claw short of the
+    /// prize along X and keeps the cheapest that lands on it.
+    fn naive_cheapest(machine: &Machine) -> Option<i128> {
+        let ((ax, ay), (bx, by), (px, py)) = *machine;
+        (0..=px / ax)
+            .filter(|a| (px - a * ax) % bx == 0)
+            .map(|a| (a, (px - a * ax) / bx))
+            .filter(|(a, b)| a * ay + b * by == py)
+            .map(|(a, b)| calculate_cost(a, b))
+            .min()
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        // three presses of B; A goes twice as far but costs three times as much
+        let machine = ((2, 2), (1, 1), (3, 3));
+        assert_eq!(Some((0, 3)), solve_machine(&machine));
+        // A goes four times as far, so it is worth pressing
+        assert_eq!(Some((2, 1)), solve_machine(&((4, 4), (1, 1), (9, 9))));
+        // the prize is off the line the claw moves along
+        assert_eq!(None, solve_machine(&((2, 2), (1, 1), (3, 4))));
+        assert_eq!(None, solve_machine(&((0, 0), (0, 0), (1, 0))));
+        assert_eq!(Some((0, 0)), solve_machine(&((0, 0), (0, 0), (0, 0))));
+        assert_eq!(Some((0, 2)), solve_machine(&((0, 0), (0, 3), (0, 6))));
+        for u in 1..12 {
+            for v in 1..12 {
+                for w in 0..100 {
+                    let machine = ((u, 2 * u), (v, 2 * v), (w, 2 * w));
+                    assert_eq!(
+                        naive_cheapest(&machine),
+                        solve_machine(&machine).map(|(a, b)| calculate_cost(a, b)),
+                        "{:?}",
+                        machine
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_moves_and_big_numbers() {
+        // button B does not move along X, which used to be divided by
+        let machine = ((2, 1), (0, 3), (4, 5));
+        assert_eq!(Some((2, 1)), solve_machine(&machine));
+        let max = i128::from(u32::MAX);
+        let machine = ((max, 1), (1, max), (max + 1, max + 1));
+        assert_eq!(Some((1, 1)), solve_machine(&machine));
+    }
+
+    #[test]
+    fn test_negative_presses() {
+        // Cramer's rule gives a = -1, b = 3 here
+        let machine = ((20, 10), (10, 20), (10, 50));
+        assert_eq!(None, solve_machine(&machine));
+        assert_eq!(None, naive_cheapest(&machine));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_machine_matches_naive(seed: u64, size in 1..10usize) {
+            let (_, machines) = parse_input(&generate(gen::input, seed, size)).unwrap();
+            for machine in &machines {
+                let presses = solve_machine(machine);
+                prop_assert_eq!(
+                    naive_cheapest(machine),
+                    presses.map(|(a, b)| calculate_cost(a, b))
+                );
+            }
+        }
+    }
+}
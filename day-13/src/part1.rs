@@ -0,0 +1,29 @@
+use aoc_common::{par::Mode, parse_complete, AocError, Solution};
+
+use crate::machine::{parse_input, total_cost, Machine};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Vec<Machine>;
+    type Output = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        total_cost(input, Mode::current())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_buttons() {
+        let input = "Button A: X+2, Y+2\nButton B: X+1, Y+1\nPrize: X=3, Y=3";
+        assert_eq!(3, Part1::solve(&Part1::parse(input).unwrap()));
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::{par::Mode, parse_complete, AocError, Solution};
+
+use crate::machine::{parse_input, total_cost, Machine};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<Machine>;
+    type Output = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let machines: Vec<Machine> = input.iter().map(with_offset).collect();
+        total_cost(&machines, Mode::current())
+    }
+}
+
+const PRIZE_OFFSET: i128 = 10000000000000;
+
+/// The machine with its prize moved to where part 2 says it really is.
+fn with_offset(machine: &Machine) -> Machine {
+    let (a, b, p) = *machine;
+    (a, b, (p.0 + PRIZE_OFFSET, p.1 + PRIZE_OFFSET))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        gen,
+        machine::{calculate_cost, solve_machine},
+    };
+
+    proptest! {
+        /// Far too many presses to brute force, so the prize is placed
+        /// where a known number of presses lands once it is offset. The
+        /// solver has to find exactly those, or ones no dearer when the
+        /// buttons move the same way.
+        #[test]
+        fn prop_offset_prize_is_found(
+            seed: u64,
+            size in 1..10usize,
+            a in 0..PRIZE_OFFSET,
+            b in 0..PRIZE_OFFSET,
+        ) {
+            let machines = Part2::parse(&generate(gen::input, seed, size)).unwrap();
+            for &((ax, ay), (bx, by), _) in &machines {
+                let prize = (a * ax + b * bx - PRIZE_OFFSET, a * ay + b * by - PRIZE_OFFSET);
+                let machine = with_offset(&((ax, ay), (bx, by), prize));
+                let presses = solve_machine(&machine);
+                if ax * by != ay * bx {
+                    prop_assert_eq!(Some((a, b)), presses);
+                    continue;
+                }
+                let Some((found_a, found_b)) = presses else {
+                    return Err(TestCaseError::fail("no presses found"));
+                };
+                prop_assert!(found_a >= 0 && found_b >= 0);
+                prop_assert_eq!(
+                    (a * ax + b * bx, a * ay + b * by),
+                    (found_a * ax + found_b * bx, found_a * ay + found_b * by)
+                );
+                prop_assert!(calculate_cost(found_a, found_b) <= calculate_cost(a, b));
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::gen::{GenRng, Rng};
+
+/// `size` robots somewhere in the 101 by 103 room, each moving up to 99
+/// tiles a second along each axis.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        out.push_str(&format!(
+            "p={},{} v={},{}\n",
+            rng.gen_range(0..101),
+            rng.gen_range(0..103),
+            rng.gen_range(-99..100),
+            rng.gen_range(-99..100)
+        ));
+    }
+    out
+}
@@ -0,0 +1,151 @@
+use aoc_common::{parse_complete, AocError, Point, Solution};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64, newline, space1},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = Vec<(Point, Point)>;
+    type Output = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, 101, 103, 100)
+    }
+}
+
+fn solve(robots: &[(Point, Point)], xlim: isize, ylim: isize, time: isize) -> isize {
+    // for each robot, simulate it
+    let final_points: Vec<_> = robots
+        .iter()
+        .map(|(p, v)| simulate_robot(*p, *v, time, xlim, ylim))
+        .collect();
+
+    // now we need to count the num of robots in each quadrant
+    let mid_x = xlim / 2;
+    let mid_y = ylim / 2;
+    let mut quad1 = 0; // top left
+    let mut quad2 = 0; // top right
+    let mut quad3 = 0; // bottom left
+    let mut quad4 = 0; // bottom right
+
+    for p in final_points {
+        let (x, y) = (p.col, p.row);
+        if x < mid_x {
+            if y < mid_y {
+                quad1 += 1;
+            } else if y > mid_y {
+                quad3 += 1;
+            }
+        } else if x > mid_x {
+            if y < mid_y {
+                quad2 += 1;
+            } else if y > mid_y {
+                quad4 += 1;
+            }
+        }
+    }
+
+    quad1 * quad2 * quad3 * quad4
+}
+
+/// Where the robot is after `t` seconds, wrapped into the room. Everything
+/// is wrapped first, the time too since the room repeats every
+/// `xlim * ylim` seconds, so no input can overflow.
+fn simulate_robot(p: Point, v: Point, t: isize, xlim: isize, ylim: isize) -> Point {
+    let wrap = |p: Point| Point::from_xy(wrap_value(p.col, xlim), wrap_value(p.row, ylim));
+    wrap(wrap(p) + wrap(v) * wrap_value(t, xlim * ylim))
+}
+
+fn wrap_value(x: isize, lim: isize) -> isize {
+    let mut res = x % lim;
+    if res < 0 {
+        res += lim;
+    }
+    res
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Point, Point)> {
+    let (input, ((px, py), (vx, vy))) = separated_pair(
+        preceded(tag("p="), separated_pair(i64, tag(","), i64)),
+        space1,
+        preceded(tag("v="), separated_pair(i64, tag(","), i64)),
+    )(input)?;
+
+    Ok((
+        input,
+        (
+            Point::from_xy(px as isize, py as isize),
+            Point::from_xy(vx as isize, vy as isize),
+        ),
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(Point, Point)>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_huge_values() {
+        let p = Point::from_xy(isize::MAX, isize::MIN);
+        let v = Point::from_xy(isize::MIN, isize::MAX);
+        let wrapped = Point::from_xy(wrap_value(isize::MAX, 11), wrap_value(isize::MIN, 7));
+        assert_eq!(wrapped, simulate_robot(p, v, 0, 11, 7));
+        // the room repeats every 77 seconds
+        let moved = simulate_robot(p, Point::from_xy(1, 0), isize::MAX, 11, 7);
+        let col = wrap_value(wrapped.col + isize::MAX % 77, 11);
+        assert_eq!(Point::from_xy(col, wrapped.row), moved);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let input = r"p=0,4 v=3,-3";
+        let ans = (Point::from_xy(0, 4), Point::from_xy(3, -3));
+        let (_, res) = parse_line(input).unwrap();
+        assert_eq!(ans, res);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let lim = 3;
+        let inputs = [-5, -2, 2, 5, 7, -7];
+        let answers = [1, 1, 2, 2, 1, 2];
+        for (i, a) in inputs.iter().zip(answers.iter()) {
+            assert_eq!(*a, wrap_value(*i, lim));
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let input = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+        let xlim = 11;
+        let ylim = 7;
+        let time = 100;
+        let (_, robots) = parse_input(input).unwrap();
+        assert_eq!(12, solve(&robots, xlim, ylim, time));
+    }
+}
@@ -0,0 +1,244 @@
+use aoc_common::{
+    par::{self, Mode},
+    parse_complete,
+    viz::{Colour, Frame},
+    AocError, Grid, Point, Solution,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64, newline, space1},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = Vec<(Point, Point)>;
+    type Output = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        solve(input, 101, 103, Mode::current())
+    }
+}
+
+fn solve(robots: &[(Point, Point)], xlim: isize, ylim: isize, mode: Mode) -> isize {
+    let times: Vec<isize> = (0..50000).collect();
+    // ties go to the earliest time in both modes
+    *par::min_by_key(mode, &times, |t| get_symmetry_score(robots, xlim, ylim, *t)).unwrap()
+}
+
+fn get_symmetry_score(robots: &[(Point, Point)], xlim: isize, ylim: isize, time: isize) -> usize {
+    // for each robot, simulate it
+    let final_points: Vec<_> = robots
+        .iter()
+        .map(|(p, v)| simulate_robot(*p, *v, time, xlim, ylim))
+        .collect();
+
+    // check if each point has a mirrored point along the xline
+
+    let mid_x = xlim / 2;
+    let mid_y = ylim / 2;
+    // now we need to count the num of robots in each quadrant
+    let mut quad1 = 0; // top left
+    let mut quad2 = 0; // top right
+    let mut quad3 = 0; // bottom left
+    let mut quad4 = 0; // bottom right
+
+    for p in final_points {
+        let (x, y) = (p.col, p.row);
+        match x {
+            x if x < mid_x => match y {
+                y if y < mid_y => quad1 += 1,
+                y if y > mid_y => quad3 += 1,
+                _ => (),
+            },
+            x if x > mid_x => match y {
+                y if y < mid_y => quad2 += 1,
+                y if y > mid_y => quad4 += 1,
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    quad1 * quad2 * quad3 * quad4
+}
+
+/// The room after each second, with the number of robots on every tile
+/// like in the puzzle text. The positions repeat after `xlim * ylim`
+/// seconds, so that is where the frames stop.
+pub fn frames(
+    robots: Vec<(Point, Point)>,
+    xlim: isize,
+    ylim: isize,
+) -> impl Iterator<Item = Frame> {
+    frames_at(robots, xlim, ylim, 0..xlim * ylim)
+}
+
+/// The `count` times whose symmetry score is lowest, best first. The
+/// picture we are looking for bunches the robots into one quadrant, so
+/// these are the frames worth looking at.
+pub fn candidates(robots: &[(Point, Point)], xlim: isize, ylim: isize, count: usize) -> Vec<isize> {
+    let mut times: Vec<(usize, isize)> = (0..xlim * ylim)
+        .map(|t| (get_symmetry_score(robots, xlim, ylim, t), t))
+        .collect();
+    times.sort_unstable();
+    times.into_iter().take(count).map(|(_, t)| t).collect()
+}
+
+/// Like [`frames`], but only after each of `times` seconds, in that order.
+pub fn frames_at(
+    robots: Vec<(Point, Point)>,
+    xlim: isize,
+    ylim: isize,
+    times: impl IntoIterator<Item = isize>,
+) -> impl Iterator<Item = Frame> {
+    times.into_iter().map(move |time| {
+        let mut counts = Grid::filled(ylim as usize, xlim as usize, 0);
+        for (p, v) in &robots {
+            counts[simulate_robot(*p, *v, time, xlim, ylim)] += 1;
+        }
+        let grid = counts.map(|n| match n {
+            0 => '.',
+            n => char::from_digit(*n, 10).unwrap_or('+'),
+        });
+        let occupied: Vec<_> = grid.points().filter(|p| grid[*p] != '.').collect();
+        let score = get_symmetry_score(&robots, xlim, ylim, time);
+        Frame::new(grid)
+            .colour(occupied, Colour::Green)
+            .with_caption(format!("{}s, symmetry score {}", time, score))
+    })
+}
+
+/// Where the robot is after `t` seconds, wrapped into the room. Everything
+/// is wrapped first, the time too since the room repeats every
+/// `xlim * ylim` seconds, so no input can overflow.
+fn simulate_robot(p: Point, v: Point, t: isize, xlim: isize, ylim: isize) -> Point {
+    let wrap = |p: Point| Point::from_xy(wrap_value(p.col, xlim), wrap_value(p.row, ylim));
+    wrap(wrap(p) + wrap(v) * wrap_value(t, xlim * ylim))
+}
+
+fn wrap_value(x: isize, lim: isize) -> isize {
+    let mut res = x % lim;
+    if res < 0 {
+        res += lim;
+    }
+    res
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Point, Point)> {
+    let (input, ((px, py), (vx, vy))) = separated_pair(
+        preceded(tag("p="), separated_pair(i64, tag(","), i64)),
+        space1,
+        preceded(tag("v="), separated_pair(i64, tag(","), i64)),
+    )(input)?;
+
+    Ok((
+        input,
+        (
+            Point::from_xy(px as isize, py as isize),
+            Point::from_xy(vx as isize, vy as isize),
+        ),
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(Point, Point)>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_huge_values() {
+        let p = Point::from_xy(isize::MAX, isize::MIN);
+        let v = Point::from_xy(isize::MIN, isize::MAX);
+        let wrapped = Point::from_xy(wrap_value(isize::MAX, 11), wrap_value(isize::MIN, 7));
+        assert_eq!(wrapped, simulate_robot(p, v, 0, 11, 7));
+        // the room repeats every 77 seconds
+        let moved = simulate_robot(p, Point::from_xy(1, 0), isize::MAX, 11, 7);
+        let col = wrap_value(wrapped.col + isize::MAX % 77, 11);
+        assert_eq!(Point::from_xy(col, wrapped.row), moved);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let input = r"p=0,4 v=3,-3";
+        let ans = (Point::from_xy(0, 4), Point::from_xy(3, -3));
+        let (_, res) = parse_line(input).unwrap();
+        assert_eq!(ans, res);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let lim = 3;
+        let inputs = [-5, -2, 2, 5, 7, -7];
+        let answers = [1, 1, 2, 2, 1, 2];
+        for (i, a) in inputs.iter().zip(answers.iter()) {
+            assert_eq!(*a, wrap_value(*i, lim));
+        }
+    }
+
+    const SAMPLE: &str = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn test_sample() {
+        let xlim = 11;
+        let ylim = 7;
+        let time = 100;
+        let (_, robots) = parse_input(SAMPLE).unwrap();
+        assert_eq!(12, get_symmetry_score(&robots, xlim, ylim, time));
+    }
+
+    #[test]
+    fn test_frames() {
+        let (_, robots) = parse_input(SAMPLE).unwrap();
+        let frames: Vec<_> = frames(robots, 11, 7).collect();
+        assert_eq!(77, frames.len());
+        // the room after 100 seconds from the puzzle text
+        let room = "\
+......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....";
+        assert_eq!(room, frames[100 % 77].grid.to_string());
+        assert!(frames[100 % 77].caption.ends_with("symmetry score 12"));
+    }
+
+    #[test]
+    fn test_candidates() {
+        let (_, robots) = parse_input(SAMPLE).unwrap();
+        let best = candidates(&robots, 11, 7, 3);
+        assert_eq!(3, best.len());
+        let scores: Vec<_> = best
+            .iter()
+            .map(|t| get_symmetry_score(&robots, 11, 7, *t))
+            .collect();
+        assert!(scores.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(best[0], solve(&robots, 11, 7, Mode::Sequential) % 77);
+        let frame = frames_at(robots, 11, 7, [best[0]]).next().unwrap();
+        assert!(frame.caption.starts_with(&format!("{}s,", best[0])));
+    }
+}
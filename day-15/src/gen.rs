@@ -0,0 +1,66 @@
+use aoc_common::gen::{GenRng, Rng, SliceRandom};
+
+/// A `size` by `size` warehouse and `10 * size` moves.
+///
+/// The outer wall is closed, so nothing can be pushed off the map, and
+/// there is exactly one robot `@`. Inside are a few walls and plenty of
+/// boxes. Moves are written 70 to a line like in the real input.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    let size = size.max(3);
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| {
+                    if r == 0 || c == 0 || r == size - 1 || c == size - 1 {
+                        '#'
+                    } else {
+                        match rng.gen_range(0..20) {
+                            0 => '#',
+                            1..=5 => 'O',
+                            _ => '.',
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let (r, c) = (rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+    rows[r][c] = '@';
+
+    let moves: Vec<char> = (0..size * 10)
+        .map(|_| *['^', 'v', '<', '>'].choose(rng).unwrap())
+        .collect();
+    let mut out: String = rows
+        .iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect();
+    out.push('\n');
+    for line in moves.chunks(70) {
+        out.extend(line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::generate;
+
+    use super::*;
+
+    #[test]
+    fn test_one_robot_inside_closed_walls() {
+        for seed in 0..5 {
+            let text = generate(input, seed, 10);
+            let (map, moves) = text.split_once("\n\n").unwrap();
+            assert_eq!(1, map.matches('@').count());
+            let rows: Vec<&str> = map.lines().collect();
+            assert!(rows[0].chars().all(|c| c == '#'));
+            assert!(rows[rows.len() - 1].chars().all(|c| c == '#'));
+            assert!(rows
+                .iter()
+                .all(|row| row.starts_with('#') && row.ends_with('#')));
+            assert_eq!(100, moves.lines().collect::<String>().len());
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use aoc_common::{
+    parse_complete,
+    viz::{Colour, Frame},
+    AocError, Direction, Grid, Point, Solution,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{anychar, line_ending, one_of},
+    combinator::map_opt,
+    error::{Error, ErrorKind},
+    multi::{many0, many1, separated_list1},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    type Input = (Grid<char>, Vec<Direction>);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (grid, moves) = parse_complete(input, parse_input)?;
+        if grid.find_all(&'@').count() != 1 {
+            return Err(AocError::InvalidInput(
+                "the map needs exactly one robot '@'".to_string(),
+            ));
+        }
+        if !walled_in(&grid) {
+            return Err(AocError::InvalidInput(
+                "the map is not surrounded by walls '#'".to_string(),
+            ));
+        }
+        Ok((grid, moves))
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let (grid, moves) = input.clone();
+        solve(grid, moves)
+    }
+}
+
+fn solve(mut grid: Grid<char>, moves: Vec<Direction>) -> usize {
+    let mut pos = get_start_point(&grid);
+
+    for mv in moves.into_iter() {
+        pos = move_thing(&mut grid, mv, pos);
+    }
+
+    // now we need to score the system
+
+    grid.find_all(&'O')
+        .map(|p| 100 * p.row as usize + p.col as usize)
+        .sum()
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Grid<char>, Vec<Direction>)> {
+    separated_pair(parse_grid, tag("\n\n"), parse_moves)(input)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Grid<char>> {
+    let (input, rows) = parse_rows(input)?;
+    Ok((input, Grid::from_rows(rows)))
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, lines) =
+        separated_list1(line_ending, many1(map_opt(anychar, Direction::from_arrow)))(input)?;
+
+    Ok((input, lines.into_iter().flatten().collect()))
+}
+
+fn parse_row(input: &str) -> IResult<&str, Vec<char>> {
+    let (rest, row) = many1(one_of("#.O@"))(input)?;
+    if !(rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n")) {
+        // an unknown cell is an error right where it is, not the end of the row
+        return Err(nom::Err::Failure(Error::new(rest, ErrorKind::OneOf)));
+    }
+    Ok((rest, row))
+}
+
+fn parse_rows(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, first) = parse_row(input)?;
+    let width = first.len();
+    let (input, rest) = many0(preceded(line_ending, |row_start| {
+        let (input, row) = parse_row(row_start)?;
+        if row.len() != width {
+            return Err(nom::Err::Failure(Error::new(row_start, ErrorKind::Verify)));
+        }
+        Ok((input, row))
+    }))(input)?;
+
+    let mut rows = vec![first];
+    rows.extend(rest);
+    Ok((input, rows))
+}
+
+fn move_thing(grid: &mut Grid<char>, dir: Direction, thing_pos: Point) -> Point {
+    // recursive function
+    // returns the position the thing was moved to
+    let current_element_copy = grid[thing_pos];
+    let next_pos = thing_pos + dir;
+    match cell(grid, next_pos) {
+        '.' => {
+            // free space to move into
+            grid[next_pos] = current_element_copy;
+            grid[thing_pos] = '.';
+            next_pos
+        }
+        '#' => {
+            //it's a wall. no movement
+            thing_pos
+        }
+        'O' => {
+            // things get interesting here.
+            // this is the recursive case
+            let moved_pos = move_thing(grid, dir, next_pos);
+            if moved_pos == next_pos {
+                // the box did not move.
+                thing_pos
+            } else {
+                // we did move. so now we can actually move as if it was a '.'
+
+                grid[next_pos] = current_element_copy;
+                grid[thing_pos] = '.';
+                next_pos
+            }
+        }
+        _ => panic!("unknown element encountered"),
+    }
+}
+
+/// The warehouse before the first move and after each one, with the robot
+/// highlighted and the boxes it just pushed in colour.
+pub fn frames(mut grid: Grid<char>, moves: Vec<Direction>) -> impl Iterator<Item = Frame> {
+    let mut pos = get_start_point(&grid);
+    let first = Frame::new(grid.clone())
+        .highlight([pos], Colour::Yellow)
+        .with_caption(format!("0 of {} moves", moves.len()));
+    let total = moves.len();
+    let rest = moves.into_iter().enumerate().map(move |(i, mv)| {
+        let before = grid.clone();
+        pos = move_thing(&mut grid, mv, pos);
+        let pushed: Vec<_> = grid
+            .points()
+            .filter(|p| *p != pos && grid[*p] != '.' && grid[*p] != before[*p])
+            .collect();
+        Frame::new(grid.clone())
+            .colour(pushed, Colour::Green)
+            .highlight([pos], Colour::Yellow)
+            .with_caption(format!("{} of {} moves, last {:?}", i + 1, total, mv))
+    });
+    std::iter::once(first).chain(rest)
+}
+
+/// Whether every cell on the edge of the map is a wall, so nothing can be
+/// pushed off it.
+fn walled_in(grid: &Grid<char>) -> bool {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    grid.row(0).iter().all(|c| *c == '#')
+        && grid.row(rows - 1).iter().all(|c| *c == '#')
+        && grid.column(0).all(|c| *c == '#')
+        && grid.column(cols - 1).all(|c| *c == '#')
+}
+
+/// The cell at `p`, with anything off the map counting as a wall.
+fn cell(grid: &Grid<char>, p: Point) -> char {
+    grid.get(p).copied().unwrap_or('#')
+}
+
+fn get_start_point(grid: &Grid<char>) -> Point {
+    grid.find(&'@').expect("bro where is the start point fr fr")
+}
+
+// fn solve(
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let input = r"#.
+O@";
+        let (_, grid) = parse_grid(input).unwrap();
+        assert_eq!(Grid::from_rows(vec![vec!['#', '.'], vec!['O', '@']]), grid);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Part1::parse("#.\nOx\n\n<>").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+        let err = Part1::parse("#.\n.@.\n\n<>").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+        let err = Part1::parse("#.\n.@\n\n<>\n^?").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::TrailingInput {
+                line: 5,
+                column: 2,
+                ..
+            }
+        ));
+        let err = Part1::parse("#.\n.O\n\n<>").unwrap_err();
+        assert!(matches!(err, AocError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_parse_open_walls() {
+        for input in [
+            "@.\n\n<",
+            "@O\n\n>",
+            "####\n#@.#\n#.##\n\n<",
+            "###\n#@.\n###\n\n>",
+        ] {
+            let err = Part1::parse(input).unwrap_err();
+            assert!(matches!(err, AocError::InvalidInput(_)), "{}", input);
+        }
+        assert!(Part1::parse("###\n#@#\n###\n\n<").is_ok());
+        let err = Part1::parse("####\n#@@#\n####\n\n>").unwrap_err();
+        assert!(matches!(err, AocError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_frames() {
+        let (grid, moves) = Part1::parse(include_str!("../data/examples/small.txt")).unwrap();
+        let frames: Vec<_> = frames(grid.clone(), moves).collect();
+        assert_eq!(16, frames.len());
+        assert_eq!(grid, frames[0].grid);
+        // the fourth move pushes the first box one to the right
+        assert_eq!(Some(Colour::Green), frames[4].style(Point::new(1, 4)).fg);
+        assert_eq!(Some(Colour::Yellow), frames[4].style(Point::new(1, 3)).bg);
+        assert_eq!(None, frames[3].style(Point::new(1, 4)).fg);
+        let output_grid = r"########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########";
+        assert_eq!(output_grid, frames[15].grid.to_string());
+    }
+}
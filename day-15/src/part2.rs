@@ -0,0 +1,453 @@
+use aoc_common::{
+    parse_complete,
+    viz::{Colour, Frame},
+    AocError, Direction, Grid, Point, Solution,
+};
+use core::panic;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{anychar, line_ending, one_of},
+    combinator::map_opt,
+    error::{Error, ErrorKind},
+    multi::{many0, many1, separated_list1},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    type Input = (Grid<char>, Vec<Direction>);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (grid, moves) = parse_complete(input, parse_input)?;
+        if grid.find_all(&'@').count() != 1 {
+            return Err(AocError::InvalidInput(
+                "the map needs exactly one robot '@'".to_string(),
+            ));
+        }
+        if !walled_in(&grid) {
+            return Err(AocError::InvalidInput(
+                "the map is not surrounded by walls '#'".to_string(),
+            ));
+        }
+        Ok((grid, moves))
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        let (grid, moves) = input.clone();
+        let (result, _) = solve(grid, moves);
+        result
+    }
+}
+
+fn solve(mut grid: Grid<char>, moves: Vec<Direction>) -> (usize, Grid<char>) {
+    let mut pos = get_start_point(&grid);
+
+    for mv in moves.into_iter() {
+        pos = move_thing_general(&mut grid, mv, pos);
+    }
+
+    // now we need to score the system
+
+    let result = grid
+        .find_all(&'[')
+        .map(|p| 100 * p.row as usize + p.col as usize)
+        .sum();
+
+    (result, grid)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Grid<char>, Vec<Direction>)> {
+    separated_pair(parse_grid, tag("\n\n"), parse_moves)(input)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Grid<char>> {
+    let (input, grid) = parse_rows(input)?;
+    let grid = grid
+        .into_iter()
+        .map(|v| {
+            v.into_iter()
+                .flat_map(|c| match c {
+                    '.' => ['.', '.'].into_iter(),
+                    '@' => ['@', '.'].into_iter(),
+                    '#' => ['#', '#'].into_iter(),
+                    'O' => ['[', ']'].into_iter(),
+                    _ => unreachable!("parse_rows only accepts #.O@"),
+                })
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    Ok((input, Grid::from_rows(grid)))
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, lines) =
+        separated_list1(line_ending, many1(map_opt(anychar, Direction::from_arrow)))(input)?;
+
+    Ok((input, lines.into_iter().flatten().collect()))
+}
+
+fn parse_row(input: &str) -> IResult<&str, Vec<char>> {
+    let (rest, row) = many1(one_of("#.O@"))(input)?;
+    if !(rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n")) {
+        // an unknown cell is an error right where it is, not the end of the row
+        return Err(nom::Err::Failure(Error::new(rest, ErrorKind::OneOf)));
+    }
+    Ok((rest, row))
+}
+
+fn parse_rows(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, first) = parse_row(input)?;
+    let width = first.len();
+    let (input, rest) = many0(preceded(line_ending, |row_start| {
+        let (input, row) = parse_row(row_start)?;
+        if row.len() != width {
+            return Err(nom::Err::Failure(Error::new(row_start, ErrorKind::Verify)));
+        }
+        Ok((input, row))
+    }))(input)?;
+
+    let mut rows = vec![first];
+    rows.extend(rest);
+    Ok((input, rows))
+}
+
+// fn move_thing_vertically(
+
+fn move_wall_vertically(grid: &mut Grid<char>, dir: Direction, thing_pos: Point) -> Point {
+    assert!(dir.is_vertical());
+    let current_element_copy = grid[thing_pos];
+    // dbg!(current_element_copy);
+    assert!(current_element_copy == '[' || current_element_copy == ']');
+
+    let left_pos = if current_element_copy == '[' {
+        thing_pos
+    } else {
+        thing_pos + Direction::W
+    };
+
+    let right_pos = left_pos + Direction::E;
+
+    // let paired_element_copy = grid[paired_pos];
+
+    // recursive function
+    // returns the position the thing was moved to
+    let next_left_pos = left_pos + dir;
+    let next_right_pos = right_pos + dir;
+    let left_element = grid[left_pos];
+    let right_element = grid[right_pos];
+
+    let next_pos = thing_pos + dir;
+
+    match (cell(grid, next_left_pos), cell(grid, next_right_pos)) {
+        ('.', '.') => {
+            // free space to move into
+            grid[next_left_pos] = left_element;
+            grid[next_right_pos] = right_element;
+            grid[left_pos] = '.';
+            grid[right_pos] = '.';
+            next_pos
+        }
+        ('#', _) | (_, '#') => {
+            //it's a wall. no movement
+            thing_pos
+        }
+        ('.', '[') => {
+            // now try to push the right wall
+            // first deduce where the right wall is relative to us
+            // let next_pos_to_push = if current_element_copy == '[' {
+            //     next_p_pos
+            // } else {
+            //     next_pos
+            // };
+            let moved_pos = move_wall_vertically(grid, dir, next_right_pos);
+            if moved_pos == next_right_pos {
+                // oh no, we could not move. ripperoni
+                thing_pos
+            } else {
+                // yay we moved
+                grid[next_left_pos] = left_element;
+                grid[next_right_pos] = right_element;
+                grid[left_pos] = '.';
+                grid[right_pos] = '.';
+                next_pos
+            }
+        }
+        (']', '.') => {
+            // now try to push the left wall
+            // first deduce where the right wall is relative to us
+            let moved_pos = move_wall_vertically(grid, dir, next_left_pos);
+            if moved_pos == next_left_pos {
+                // oh no, we could not move. ripperoni
+                thing_pos
+            } else {
+                // yay we moved
+                grid[next_left_pos] = left_element;
+                grid[next_right_pos] = right_element;
+                grid[left_pos] = '.';
+                grid[right_pos] = '.';
+                next_pos
+            }
+        }
+
+        ('[', ']') => {
+            // now try to push the wall right ahead of us
+            // should not matter if we're pushing the left or right wall.
+            // picking left arbitrarilly
+            let moved_pos = move_wall_vertically(grid, dir, next_left_pos);
+            if moved_pos == next_left_pos {
+                // oh no, we could not move. ripperoni
+                thing_pos
+            } else {
+                // yay we moved
+                grid[next_left_pos] = left_element;
+                grid[next_right_pos] = right_element;
+                grid[left_pos] = '.';
+                grid[right_pos] = '.';
+                next_pos
+            }
+        }
+        (']', '[') => {
+            // we need a separate logic to see if the blocks can be moved. because if ether cannot move, neither should
+            // if we only use the move function, we may mistakenly push one of the above boxes when the other one cannot
+            // move
+            if can_move_wall_vertically(grid, dir, next_left_pos)
+                && can_move_wall_vertically(grid, dir, next_right_pos)
+            {
+                // now we execute the moves
+                move_wall_vertically(grid, dir, next_left_pos);
+                move_wall_vertically(grid, dir, next_right_pos);
+                // yay we moved
+                grid[next_left_pos] = left_element;
+                grid[next_right_pos] = right_element;
+                grid[left_pos] = '.';
+                grid[right_pos] = '.';
+                next_pos
+            } else {
+                thing_pos
+            }
+        }
+        _ => panic!("unknown element combo encountered"),
+    }
+}
+fn can_move_wall_vertically(grid: &mut Grid<char>, dir: Direction, thing_pos: Point) -> bool {
+    assert!(dir.is_vertical());
+    let current_element_copy = grid[thing_pos];
+    // dbg!(current_element_copy);
+    assert!(current_element_copy == '[' || current_element_copy == ']');
+
+    let left_pos = if current_element_copy == '[' {
+        thing_pos
+    } else {
+        thing_pos + Direction::W
+    };
+
+    let right_pos = left_pos + Direction::E;
+    let next_left_pos = left_pos + dir;
+    let next_right_pos = right_pos + dir;
+
+    match (cell(grid, next_left_pos), cell(grid, next_right_pos)) {
+        ('.', '.') => true,
+        ('#', _) | (_, '#') => false,
+        ('.', '[') => can_move_wall_vertically(grid, dir, next_right_pos),
+        (']', '.') => can_move_wall_vertically(grid, dir, next_left_pos),
+        ('[', ']') => can_move_wall_vertically(grid, dir, next_left_pos),
+        (']', '[') => {
+            can_move_wall_vertically(grid, dir, next_left_pos)
+                && can_move_wall_vertically(grid, dir, next_right_pos)
+        }
+        _ => panic!("unknown element combo encountered"),
+    }
+}
+
+fn move_thing_general(grid: &mut Grid<char>, dir: Direction, thing_pos: Point) -> Point {
+    let current_element_copy = grid[thing_pos];
+    if current_element_copy != '@' {
+        // if we're not moving the robot, we better be moving a box horizontally
+        assert!(dir.is_horizontal());
+    }
+    // recursive function
+    // returns the position the thing was moved to
+    let next_pos = thing_pos + dir;
+    match cell(grid, next_pos) {
+        '.' => {
+            // free space to move into
+            grid[next_pos] = current_element_copy;
+            grid[thing_pos] = '.';
+            next_pos
+        }
+        '#' => {
+            //it's a wall. no movement
+            thing_pos
+        }
+        '[' | ']' => {
+            // diff cases if moving vertically or horizontally
+            let moved_pos = match dir {
+                Direction::N | Direction::S => move_wall_vertically(grid, dir, next_pos),
+                Direction::E | Direction::W => move_thing_general(grid, dir, next_pos),
+                _ => panic!("we should not get a dir that is not one of these"),
+            };
+
+            if moved_pos == next_pos {
+                // the box did not move.
+                thing_pos
+            } else {
+                // we did move. so now we can actually move as if it was a '.'
+
+                grid[next_pos] = current_element_copy;
+                grid[thing_pos] = '.';
+                next_pos
+            }
+        }
+        _ => panic!("unknown element encountered"),
+    }
+}
+
+/// The warehouse before the first move and after each one, with the robot
+/// highlighted and the boxes it just pushed in colour.
+pub fn frames(mut grid: Grid<char>, moves: Vec<Direction>) -> impl Iterator<Item = Frame> {
+    let mut pos = get_start_point(&grid);
+    let first = Frame::new(grid.clone())
+        .highlight([pos], Colour::Yellow)
+        .with_caption(format!("0 of {} moves", moves.len()));
+    let total = moves.len();
+    let rest = moves.into_iter().enumerate().map(move |(i, mv)| {
+        let before = grid.clone();
+        pos = move_thing_general(&mut grid, mv, pos);
+        let pushed: Vec<_> = grid
+            .points()
+            .filter(|p| *p != pos && grid[*p] != '.' && grid[*p] != before[*p])
+            .collect();
+        Frame::new(grid.clone())
+            .colour(pushed, Colour::Green)
+            .highlight([pos], Colour::Yellow)
+            .with_caption(format!("{} of {} moves, last {:?}", i + 1, total, mv))
+    });
+    std::iter::once(first).chain(rest)
+}
+
+/// Whether every cell on the edge of the map is a wall, so nothing can be
+/// pushed off it.
+fn walled_in(grid: &Grid<char>) -> bool {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    grid.row(0).iter().all(|c| *c == '#')
+        && grid.row(rows - 1).iter().all(|c| *c == '#')
+        && grid.column(0).all(|c| *c == '#')
+        && grid.column(cols - 1).all(|c| *c == '#')
+}
+
+/// The cell at `p`, with anything off the map counting as a wall.
+fn cell(grid: &Grid<char>, p: Point) -> char {
+    grid.get(p).copied().unwrap_or('#')
+}
+
+fn get_start_point(grid: &Grid<char>) -> Point {
+    grid.find(&'@').expect("bro where is the start point fr fr")
+}
+
+// fn solve(
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_open_walls() {
+        for input in ["@.\n\n<", "#O@\n\n>", "####\n#@.#\n#.O.\n####\n\n<"] {
+            let err = Part2::parse(input).unwrap_err();
+            assert!(matches!(err, AocError::InvalidInput(_)), "{}", input);
+        }
+        assert!(Part2::parse("###\n#@#\n###\n\n<").is_ok());
+        let err = Part2::parse("####\n#@@#\n####\n\n>").unwrap_err();
+        assert!(matches!(err, AocError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_big_sample() {
+        let input = r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+        let (_, (grid, moves)) = parse_input(input).unwrap();
+        let (result, grid) = solve(grid, moves);
+        assert_eq!(9021, result);
+        let output_grid = r"####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################";
+        assert_eq!(output_grid, grid.to_string());
+        println!("{}", grid);
+    }
+
+    #[test]
+    fn test_small() {
+        let input = r"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let (_, (grid, moves)) = parse_input(input).unwrap();
+        let (_, grid) = solve(grid, moves);
+        // assert_eq!(9021, result);
+        let output_grid = r"##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############";
+        assert_eq!(output_grid, grid.to_string());
+        println!("{}", grid);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = r"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let (grid, moves) = Part2::parse(input).unwrap();
+        let frames: Vec<_> = frames(grid.clone(), moves.clone()).collect();
+        assert_eq!(moves.len() + 1, frames.len());
+        assert_eq!(grid, frames[0].grid);
+        assert_eq!(solve(grid, moves).1, frames.last().unwrap().grid);
+        assert_eq!("11 of 11 moves, last N", frames[11].caption);
+    }
+}
@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    aoc_fuzz::parse_and_solve::<day_05::part1::Part1>(data);
+});
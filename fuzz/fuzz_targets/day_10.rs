@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    aoc_fuzz::parse_and_solve::<day_10::part1::Part1>(data);
+    aoc_fuzz::parse_and_solve::<day_10::part2::Part2>(data);
+});
@@ -0,0 +1,18 @@
+#!/bin/sh
+# Seeds corpus/day_NN for every fuzz target with the day's puzzle input and
+# examples, plus a CRLF copy of each so both line endings get explored.
+set -eu
+
+cd "$(dirname "$0")"
+for day in ../day-*/; do
+    nn=$(basename "$day" | cut -d- -f2)
+    corpus="corpus/day_$nn"
+    mkdir -p "$corpus"
+    for file in "$day"data/*.txt "$day"data/examples/*.txt; do
+        [ -f "$file" ] || continue
+        name=$(basename "$file" .txt)
+        cp "$file" "$corpus/$name"
+        sed 's/$/\r/' "$file" > "$corpus/$name-crlf"
+    done
+done
+echo "seeded $(ls corpus | wc -l) corpora"
@@ -0,0 +1,30 @@
+//! libFuzzer targets for the parsers and solvers, one per day.
+//!
+//! Each target feeds arbitrary bytes to both parts' [`Solution::parse`] and
+//! fails on any panic, since bad input must only ever come back as an
+//! [`AocError`](aoc_common::AocError). Whatever parses is solved too, so
+//! accepting an input means the solver can handle it. Run from this
+//! directory with
+//!
+//! ```text
+//! ./seed-corpus.sh
+//! cargo +nightly fuzz run day_09
+//! ```
+
+use aoc_common::Solution;
+
+/// Parses `data` as the puzzle input of `S`, invalid UTF-8 and all, and
+/// solves it if it parses.
+pub fn parse_and_solve<S: Solution>(data: &[u8]) {
+    let input = String::from_utf8_lossy(data);
+    match S::parse(&input) {
+        Ok(parsed) => {
+            let _ = S::solve(&parsed).to_string();
+        }
+        // rendering the error slices the input to locate it, which must not
+        // panic either
+        Err(err) => {
+            let _ = err.to_string();
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use aoc_common::{AocError, Solver};
+
+/// Accepted answers keyed by day and part, as recorded in `answers.toml`.
+///
+/// Each day is a `[day-XX]` table with `part1`/`part2` keys. Values may be
+/// written as strings or integers and are compared against the rendered
+/// answer.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Answers::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let table: toml::Table = text.parse().map_err(|err: toml::de::Error| {
+            let start = err.span().map_or(text.len(), |span| span.start);
+            let expected = format!("valid TOML ({})", err.message().trim_end());
+            AocError::parse_at(text, &text[start..], expected)
+        })?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = number_after(&day_key, "day-").ok_or_else(|| {
+                AocError::InvalidInput(format!("expected a [day-XX] table, found [{}]", day_key))
+            })?;
+            let parts = parts.as_table().ok_or_else(|| {
+                AocError::InvalidInput(format!("{} should be a table of parts", day_key))
+            })?;
+            for (part_key, value) in parts {
+                let part = number_after(part_key, "part").ok_or_else(|| {
+                    AocError::InvalidInput(format!(
+                        "expected part1 or part2 in [{}], found {}",
+                        day_key, part_key
+                    ))
+                })?;
+                let answer = match value {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(n) => n.to_string(),
+                    other => {
+                        return Err(AocError::InvalidInput(format!(
+                            "{}.{} should be a string or integer, found {}",
+                            day_key,
+                            part_key,
+                            other.type_str()
+                        )))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    /// Runs `solver` on its puzzle input under `root` and compares the
+    /// answer with the recorded one.
+    pub fn verify(&self, solver: &Solver, root: &Path) -> Verification {
+        let path = root.join(solver.default_input());
+        let result = fs::read_to_string(&path)
+            .map_err(|source| AocError::Io { path, source })
+            .and_then(|input| solver.run(&input));
+        let (answer, status) = match (result, self.get(solver.day, solver.part)) {
+            (Err(err), _) => (String::new(), Status::Error(err)),
+            (Ok(answer), None) => (answer, Status::Unrecorded),
+            (Ok(answer), Some(expected)) if answer == expected => (answer, Status::Ok),
+            (Ok(answer), Some(_)) => (answer, Status::Mismatch),
+        };
+        Verification { answer, status }
+    }
+}
+
+/// A solver's answer and how it compares with the recorded one.
+#[derive(Debug)]
+pub struct Verification {
+    /// Empty if the solver failed.
+    pub answer: String,
+    pub status: Status,
+}
+
+#[derive(Debug)]
+pub enum Status {
+    Ok,
+    Unrecorded,
+    Mismatch,
+    Error(AocError),
+}
+
+fn number_after(key: &str, prefix: &str) -> Option<u8> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("[day-03]\npart1 = \"12\"\npart2 = 34\n").unwrap();
+        assert_eq!(Some("12"), answers.get(3, 1));
+        assert_eq!(Some("34"), answers.get(3, 2));
+        assert_eq!(None, answers.get(4, 1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Answers::parse("[day-01]\npart1 = \n"),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("[monday]\npart1 = 1\n"),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[day-01]\nfirst = 1\n"),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[day-01]\npart1 = 1.5\n"),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify() {
+        let root = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(root.join("day-01/data")).unwrap();
+        let sample = include_str!("../day-01/data/examples/sample.txt");
+        fs::write(root.join("day-01/data/day-01.txt"), sample).unwrap();
+        let part1 = registry::find(1, 1).unwrap();
+        let part2 = registry::find(1, 2).unwrap();
+
+        let answers = Answers::parse("[day-01]\npart1 = 11\n").unwrap();
+        let verification = answers.verify(part1, &root);
+        assert_eq!("11", verification.answer);
+        assert!(matches!(verification.status, Status::Ok));
+        assert!(matches!(
+            answers.verify(part2, &root).status,
+            Status::Unrecorded
+        ));
+        let answers = Answers::parse("[day-01]\npart1 = 12\n").unwrap();
+        assert!(matches!(
+            answers.verify(part1, &root).status,
+            Status::Mismatch
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(
+            answers.verify(part1, &root).status,
+            Status::Error(AocError::Io { .. })
+        ));
+    }
+
+    /// The same check as `aoc verify`, for every day whose puzzle input is
+    /// here. Solving them all is slow in a debug build, so it only runs
+    /// with `cargo test -- --ignored`.
+    #[test]
+    #[ignore = "solves every puzzle input"]
+    fn test_recorded_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let answers = Answers::load(&root.join("answers.toml")).unwrap();
+        let solvers: Vec<_> = registry::SOLVERS
+            .iter()
+            .filter(|solver| root.join(solver.default_input()).exists())
+            .collect();
+        assert!(!solvers.is_empty(), "no puzzle inputs to verify against");
+        for solver in solvers {
+            let verification = answers.verify(solver, root);
+            assert!(
+                matches!(verification.status, Status::Ok),
+                "day {} part {} answered {:?}: {:?}",
+                solver.day,
+                solver.part,
+                verification.answer,
+                verification.status
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_solver_has_an_answer() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml");
+        let answers = Answers::load(&path).unwrap();
+        for solver in registry::SOLVERS {
+            assert!(
+                answers.get(solver.day, solver.part).is_some(),
+                "no answer recorded for day {} part {}",
+                solver.day,
+                solver.part
+            );
+        }
+    }
+}
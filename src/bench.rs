@@ -0,0 +1,196 @@
+use std::{fs, path::Path, time::Duration};
+
+use aoc_common::{AocError, Solver};
+use serde::{Deserialize, Serialize};
+
+/// Summary of a set of timings, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ms: f64,
+    pub median_ms: f64,
+    pub stddev_ms: f64,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// If `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(f64::total_cmp);
+
+        let n = ms.len();
+        let mean = ms.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (ms[n / 2 - 1] + ms[n / 2]) / 2.0
+        } else {
+            ms[n / 2]
+        };
+        // sample standard deviation, so a single run has none
+        let stddev = if n > 1 {
+            let var = ms.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            var.sqrt()
+        } else {
+            0.0
+        };
+
+        Stats {
+            mean_ms: mean,
+            median_ms: median,
+            stddev_ms: stddev,
+        }
+    }
+}
+
+/// Timings for one day/part, and the unit saved to a baseline file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchResult {
+    pub fn total_mean_ms(&self) -> f64 {
+        self.parse.mean_ms + self.solve.mean_ms
+    }
+}
+
+/// Parses and solves `input` `runs` times, timing each phase separately.
+pub fn bench(solver: &Solver, input: &str, runs: usize) -> Result<BenchResult, AocError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let timed = solver.run_timed(input)?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+    }
+    Ok(BenchResult {
+        day: solver.day,
+        part: solver.part,
+        runs,
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+pub fn save_baseline(path: &Path, results: &[BenchResult]) -> Result<(), AocError> {
+    let json = serde_json::to_string_pretty(results).expect("bench results always serialise");
+    fs::write(path, json + "\n").map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn load_baseline(path: &Path) -> Result<Vec<BenchResult>, AocError> {
+    let json = fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&json).map_err(|err| {
+        AocError::InvalidInput(format!(
+            "{} is not a benchmark baseline: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+/// Relative change in total mean time against the matching baseline entry.
+pub fn change_from(result: &BenchResult, baseline: &[BenchResult]) -> Option<f64> {
+    baseline
+        .iter()
+        .find(|b| b.day == result.day && b.part == result.part)
+        .filter(|b| b.total_mean_ms() > 0.0)
+        .map(|b| result.total_mean_ms() / b.total_mean_ms() - 1.0)
+}
+
+pub fn print_header(with_baseline: bool) {
+    print!(
+        "{:>3} {:>4} {:>5} {:>11} {:>10} {:>10} {:>11} {:>10} {:>10}",
+        "day", "part", "runs", "parse mean", "median", "stddev", "solve mean", "median", "stddev"
+    );
+    if with_baseline {
+        print!(" {:>9}", "change");
+    }
+    println!();
+}
+
+pub fn print_row(result: &BenchResult, baseline: Option<&[BenchResult]>) {
+    print!(
+        "{:>3} {:>4} {:>5} {:>9.3}ms {:>8.3}ms {:>8.3}ms {:>9.3}ms {:>8.3}ms {:>8.3}ms",
+        result.day,
+        result.part,
+        result.runs,
+        result.parse.mean_ms,
+        result.parse.median_ms,
+        result.parse.stddev_ms,
+        result.solve.mean_ms,
+        result.solve.median_ms,
+        result.solve.stddev_ms,
+    );
+    if let Some(baseline) = baseline {
+        match change_from(result, baseline) {
+            Some(change) => print!(" {:>+8.1}%", change * 100.0),
+            None => print!(" {:>9}", "new"),
+        }
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    fn result(day: u8, parse_ms: f64, solve_ms: f64) -> BenchResult {
+        let stats = |mean_ms| Stats {
+            mean_ms,
+            median_ms: mean_ms,
+            stddev_ms: 0.0,
+        };
+        BenchResult {
+            day,
+            part: 1,
+            runs: 1,
+            parse: stats(parse_ms),
+            solve: stats(solve_ms),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(2.5, stats.mean_ms);
+        assert_eq!(2.5, stats.median_ms);
+        assert!((stats.stddev_ms - 1.290_994).abs() < 1e-6);
+
+        let stats = Stats::from_samples(&ms(&[7]));
+        assert_eq!(7.0, stats.median_ms);
+        assert_eq!(0.0, stats.stddev_ms);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let results = vec![result(1, 1.0, 2.0), result(2, 0.5, 0.5)];
+        save_baseline(&path, &results).unwrap();
+        let loaded = load_baseline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(results, loaded);
+    }
+
+    #[test]
+    fn test_change_from() {
+        let baseline = vec![result(1, 1.0, 3.0)];
+        let change = change_from(&result(1, 1.0, 1.0), &baseline).unwrap();
+        assert!((change + 0.5).abs() < 1e-9);
+        assert_eq!(None, change_from(&result(2, 1.0, 1.0), &baseline));
+    }
+}
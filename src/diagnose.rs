@@ -0,0 +1,62 @@
+use std::ops::RangeInclusive;
+
+use day_02::{
+    dampener::Dampener,
+    diagnose::{annotate, diagnose, Style, Summary},
+};
+
+/// Reads `--steps LOW-HIGH`.
+pub fn parse_steps(steps: &str) -> Result<RangeInclusive<u64>, String> {
+    let bounds = steps
+        .split_once('-')
+        .and_then(|(low, high)| Some((low.trim().parse().ok()?, high.trim().parse().ok()?)));
+    match bounds {
+        Some((low, high)) if low <= high => Ok(low..=high),
+        _ => Err(format!(
+            "expected LOW-HIGH with LOW <= HIGH, got '{}'",
+            steps
+        )),
+    }
+}
+
+/// Prints every report annotated with its diagnosis, in colour on a
+/// terminal, and then how many got each kind. With `summary_only` set, only
+/// the counts are printed.
+pub fn print(reports: &[Vec<u64>], dampener: &Dampener, summary_only: bool) {
+    let style = if termion::is_tty(&std::io::stdout()) {
+        Style::Ansi
+    } else {
+        Style::Plain
+    };
+    let mut summary = Summary::default();
+    for report in reports {
+        let diagnosis = diagnose(dampener, report);
+        if !summary_only {
+            println!("{}", annotate(report, &diagnosis, style));
+        }
+        summary.add(&diagnosis);
+    }
+    if !summary_only {
+        println!();
+    }
+    println!("safe: {}", summary.safe);
+    println!("safe after removing: {}", summary.safe_after_removing);
+    println!("unsafe: {}", summary.unsafe_count());
+    for (reason, count) in &summary.unsafe_reports {
+        println!("  {}: {}", reason, count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_steps() {
+        assert_eq!(Ok(1..=3), parse_steps("1-3"));
+        assert_eq!(Ok(0..=0), parse_steps(" 0 - 0 "));
+        assert!(parse_steps("3-1").is_err());
+        assert!(parse_steps("3").is_err());
+        assert!(parse_steps("-1-3").is_err());
+    }
+}
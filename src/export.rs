@@ -0,0 +1,199 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::Path,
+};
+
+use aoc_common::{
+    viz::{Frame, Image, Palette, Rgb},
+    AocError,
+};
+
+/// Writes each frame to `dir/frame-NNNN.png`, creating `dir` if needed, and
+/// returns how many frames were written.
+pub fn write_pngs(
+    frames: impl IntoIterator<Item = Frame>,
+    dir: &Path,
+    palette: &Palette,
+    scale: u32,
+) -> Result<usize, AocError> {
+    fs::create_dir_all(dir).map_err(|source| AocError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    let mut count = 0;
+    for (i, frame) in frames.into_iter().enumerate() {
+        let path = dir.join(format!("frame-{:04}.png", i));
+        write_png(&path, &frame.to_image(palette, scale))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+pub fn write_png(path: &Path, image: &Image) -> Result<(), AocError> {
+    let file = create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), image.width, image.height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = image.pixels.iter().flatten().copied().collect();
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(|err| encode_error(path, err))
+}
+
+/// Writes the frames to `path` as an animated GIF that loops forever,
+/// showing `fps` frames a second, and returns how many frames it holds.
+pub fn write_gif(
+    frames: impl IntoIterator<Item = Frame>,
+    path: &Path,
+    palette: &Palette,
+    scale: u32,
+    fps: u32,
+) -> Result<usize, AocError> {
+    // every pixel comes from the palette, so one colour table covers all
+    // frames and nothing needs quantising
+    let colours = palette.colours();
+    if colours.len() > 256 {
+        return Err(AocError::Usage(format!(
+            "a GIF holds at most 256 colours, the palette has {}",
+            colours.len()
+        )));
+    }
+    let index: HashMap<Rgb, u8> = colours
+        .iter()
+        .enumerate()
+        .map(|(i, rgb)| (*rgb, i as u8))
+        .collect();
+    let table: Vec<u8> = colours.iter().flatten().copied().collect();
+    // GIF delays are in hundredths of a second
+    let delay = (100 / fps.max(1)).max(1) as u16;
+
+    let mut encoder = None;
+    let mut count = 0;
+    for frame in frames {
+        // checked before drawing, so a huge scale fails fast
+        let size = |cells: usize| u16::try_from(cells as u64 * scale.max(1) as u64);
+        let (Ok(width), Ok(height)) = (size(frame.grid.cols()), size(frame.grid.rows())) else {
+            return Err(AocError::Usage(format!(
+                "a {}x{} grid at scale {} is too large for a GIF, try a smaller --scale",
+                frame.grid.cols(),
+                frame.grid.rows(),
+                scale
+            )));
+        };
+        let image = frame.to_image(palette, scale);
+        if encoder.is_none() {
+            let file = BufWriter::new(create(path)?);
+            let mut gif = gif::Encoder::new(file, width, height, &table)
+                .map_err(|err| encode_error(path, err))?;
+            gif.set_repeat(gif::Repeat::Infinite)
+                .map_err(|err| encode_error(path, err))?;
+            encoder = Some(gif);
+        }
+        let buffer: Vec<u8> = image.pixels.iter().map(|rgb| index[rgb]).collect();
+        let gif_frame = gif::Frame {
+            width,
+            height,
+            delay,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        encoder
+            .as_mut()
+            .expect("created before the first frame")
+            .write_frame(&gif_frame)
+            .map_err(|err| encode_error(path, err))?;
+        count += 1;
+    }
+    if count == 0 {
+        return Err(AocError::Usage("there are no frames to export".to_string()));
+    }
+    Ok(count)
+}
+
+fn create(path: &Path) -> Result<File, AocError> {
+    File::create(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn encode_error(path: &Path, err: impl std::error::Error) -> AocError {
+    AocError::Io {
+        path: path.to_path_buf(),
+        source: io::Error::other(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use aoc_common::{viz::Colour, Grid, Point};
+
+    use super::*;
+
+    fn frames() -> impl Iterator<Item = Frame> {
+        (0..3).map(|i| {
+            Frame::new(Grid::parse("#.\n.O").unwrap())
+                .highlight([Point::new(0, i % 2)], Colour::Red)
+        })
+    }
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-export-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_write_pngs() {
+        let dir = scratch_dir("png");
+        let palette: Palette = "O=c0a000".parse().unwrap();
+        assert_eq!(3, write_pngs(frames(), &dir, &palette, 3).unwrap());
+
+        let decoder = png::Decoder::new(File::open(dir.join("frame-0001.png")).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((6, 6), (info.width, info.height));
+        let pixel = |x: usize, y: usize| &data[(y * 6 + x) * 3..(y * 6 + x) * 3 + 3];
+        assert_eq!(&[128, 128, 128], pixel(0, 0));
+        assert_eq!(&Colour::Red.rgb(), pixel(5, 2));
+        assert_eq!(&[0xc0, 0xa0, 0], pixel(5, 5));
+        assert!(!dir.join("frame-0003.png").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let dir = scratch_dir("gif");
+        let path = dir.join("walk.gif");
+        assert_eq!(
+            3,
+            write_gif(frames(), &path, &Palette::default(), 2, 20).unwrap()
+        );
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((4, 4), (decoder.width(), decoder.height()));
+        let mut count = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(5, frame.delay);
+            let red = Colour::Red.rgb();
+            let x = count % 2 * 2;
+            assert_eq!(&red, &frame.buffer[x * 4..x * 4 + 3]);
+            count += 1;
+        }
+        assert_eq!(3, count);
+
+        let err = write_gif(frames(), &path, &Palette::default(), 40000, 20).unwrap_err();
+        assert!(matches!(err, AocError::Usage(_)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::AocError;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2024";
+/// The puzzle server asks for no more than one request every few seconds.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+/// Touched on every request, so the rate limit holds across runs.
+const STAMP_FILE: &str = ".aoc-last-request";
+
+/// How we talk to the puzzle server, so tests can swap in something local.
+pub trait Http {
+    /// Fetches `url` with `session` as the session cookie and returns the
+    /// response body.
+    fn get(&self, url: &str, session: &str) -> Result<String, AocError>;
+
+    /// Posts `form` to `url` as `application/x-www-form-urlencoded` and
+    /// returns the response body.
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, AocError>;
+}
+
+/// The real backend.
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Ureq {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+}
+
+impl Ureq {
+    fn read(url: &str, result: Result<ureq::Response, ureq::Error>) -> Result<String, AocError> {
+        let fail = |message: String| AocError::Fetch {
+            url: url.to_string(),
+            message,
+        };
+        let response = result.map_err(|err| match err {
+            ureq::Error::Status(404, _) => fail("not found, is the puzzle unlocked yet?".into()),
+            ureq::Error::Status(code, _) => fail(format!("server returned {}", code)),
+            ureq::Error::Transport(err) => fail(err.to_string()),
+        })?;
+        response
+            .into_string()
+            .map_err(|err| fail(format!("could not read the response: {}", err)))
+    }
+
+    fn cookie(session: &str) -> String {
+        format!("session={}", session)
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, AocError> {
+        let result = self
+            .agent
+            .get(url)
+            .set("Cookie", &Ureq::cookie(session))
+            .call();
+        Ureq::read(url, result)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, AocError> {
+        let result = self
+            .agent
+            .post(url)
+            .set("Cookie", &Ureq::cookie(session))
+            .send_form(form);
+        Ureq::read(url, result)
+    }
+}
+
+/// The server to talk to, from `AOC_BASE_URL`.
+pub fn base_url_from_env() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// The session token from `AOC_SESSION`, if one is set.
+pub fn session_from_env() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// The error for a request that needs a session token when none is set.
+pub fn no_session(what: &str) -> AocError {
+    AocError::Usage(format!("set {} to {}", SESSION_VAR, what))
+}
+
+/// Spaces requests at least `interval` apart, recording the time of the
+/// last one in a stamp file under `root` so separate runs share the limit.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    stamp: PathBuf,
+    pub interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(root: &Path) -> Self {
+        Throttle {
+            stamp: root.join(STAMP_FILE),
+            interval: DEFAULT_INTERVAL,
+        }
+    }
+
+    /// Sleeps until the interval since the last request has passed, then
+    /// records this one.
+    pub fn wait_for_turn(&self) -> Result<(), AocError> {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        // a stamp from the future counts as just now
+        let since = last.map(|last| {
+            SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO)
+        });
+        if let Some(since) = since.filter(|since| *since < self.interval) {
+            thread::sleep(self.interval - since);
+        }
+        // rounded up, so the next wait is never cut short
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            + 1;
+        fs::write(&self.stamp, now.to_string()).map_err(|source| AocError::Io {
+            path: self.stamp.clone(),
+            source,
+        })
+    }
+}
+
+/// A stand-in for the puzzle server for tests.
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        sync::{Arc, Mutex},
+        thread::{self, JoinHandle},
+    };
+
+    /// What the mock saw of a request.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Serves every request with `handler` on a local port and keeps a log
+    /// of the requests it saw.
+    pub struct MockServer {
+        pub base_url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+        server: Arc<tiny_http::Server>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl MockServer {
+        pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+            let server = Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+            let port = server.server_addr().to_ip().unwrap().port();
+            let requests = Arc::new(Mutex::new(vec![]));
+            let handle = {
+                let server = Arc::clone(&server);
+                let requests = Arc::clone(&requests);
+                thread::spawn(move || {
+                    for mut incoming in server.incoming_requests() {
+                        let mut body = String::new();
+                        let _ = incoming.as_reader().read_to_string(&mut body);
+                        let request = Request {
+                            method: incoming.method().to_string(),
+                            url: incoming.url().to_string(),
+                            cookie: incoming
+                                .headers()
+                                .iter()
+                                .find(|h| h.field.equiv("Cookie"))
+                                .map(|h| h.value.to_string()),
+                            body,
+                        };
+                        let (status, text) = handler(&request);
+                        requests.lock().unwrap().push(request);
+                        let response =
+                            tiny_http::Response::from_string(text).with_status_code(status);
+                        let _ = incoming.respond(response);
+                    }
+                })
+            };
+            MockServer {
+                base_url: format!("http://127.0.0.1:{}/2024", port),
+                requests,
+                server,
+                handle: Some(handle),
+            }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        pub fn hits(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    impl Drop for MockServer {
+        fn drop(&mut self) {
+            self.server.unblock();
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::{mock::MockServer, *};
+
+    #[test]
+    fn test_ureq_against_mock() {
+        let server = MockServer::start(|req| match req.method.as_str() {
+            "GET" => (200, "got".to_string()),
+            _ => (500, String::new()),
+        });
+        let http = Ureq::default();
+        let url = format!("{}/x", server.base_url);
+        assert_eq!("got", http.get(&url, "abc").unwrap());
+        let err = http.post_form(&url, "abc", &[("a", "1 2")]).unwrap_err();
+        assert!(matches!(&err, AocError::Fetch { message, .. } if message.contains("500")));
+
+        let requests = server.requests();
+        assert_eq!(Some("session=abc"), requests[0].cookie.as_deref());
+        assert_eq!("a=1+2", requests[1].body);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let root = env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let mut throttle = Throttle::new(&root);
+        throttle.interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        throttle.wait_for_turn().unwrap();
+        // a second throttle reads the same stamp, like a second run would
+        throttle.clone().wait_for_turn().unwrap();
+        assert!(start.elapsed() >= throttle.interval);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::AocError;
+
+use crate::http::{self, Http, Throttle, Ureq};
+
+/// Finds puzzle inputs in the `day-XX/data` directories, downloading the
+/// ones that are missing.
+///
+/// An input that is already on disk is never fetched again. Requests are
+/// spaced at least `interval` apart, even across separate runs, and inputs
+/// are written atomically so an interrupted fetch cannot leave half an input
+/// behind.
+pub struct Inputs<H: Http> {
+    root: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    throttle: Throttle,
+    http: H,
+}
+
+impl Inputs<Ureq> {
+    /// Reads the session token and server from `AOC_SESSION` and
+    /// `AOC_BASE_URL`.
+    pub fn from_env(root: &Path) -> Self {
+        Inputs::new(
+            root,
+            &http::base_url_from_env(),
+            http::session_from_env(),
+            Ureq::default(),
+        )
+    }
+}
+
+impl<H: Http> Inputs<H> {
+    pub fn new(root: &Path, base_url: &str, session: Option<String>, http: H) -> Self {
+        Inputs {
+            root: root.to_path_buf(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle: Throttle::new(root),
+            http,
+        }
+    }
+
+    /// Where the input for `day` is cached.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root
+            .join(format!("day-{:02}/data/day-{:02}.txt", day, day))
+    }
+
+    /// An empty file is what `aoc new` leaves behind, so it does not count.
+    pub fn is_cached(&self, day: u8) -> bool {
+        fs::metadata(self.path(day)).is_ok_and(|meta| meta.len() > 0)
+    }
+
+    /// The path to the input for `day`, fetching it first if it is not
+    /// cached yet.
+    pub fn resolve(&self, day: u8) -> Result<PathBuf, AocError> {
+        let path = self.path(day);
+        if !self.is_cached(day) {
+            let input = self.fetch(day)?;
+            write_atomic(&path, &input)?;
+        }
+        Ok(path)
+    }
+
+    fn fetch(&self, day: u8) -> Result<String, AocError> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| http::no_session(&format!("download the input for day {}", day)))?;
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        self.throttle.wait_for_turn()?;
+        let input = self.http.get(&url, session)?;
+        if input.is_empty() {
+            return Err(AocError::Fetch {
+                url,
+                message: "the server sent an empty input".to_string(),
+            });
+        }
+        Ok(input)
+    }
+}
+
+/// Writes to a temporary file next to `path` and renames it into place.
+pub fn write_atomic(path: &Path, contents: &str) -> Result<(), AocError> {
+    let io_err = |source: io::Error| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_err)?;
+    }
+    let tmp = path.with_extension(format!("tmp-{}", std::process::id()));
+    let result = fs::File::create(&tmp)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result.map_err(io_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+    use crate::http::mock::MockServer;
+
+    /// Serves `input N` for any day but 25, to a client with the right
+    /// session cookie.
+    fn server() -> MockServer {
+        MockServer::start(|req| {
+            let day = req
+                .url
+                .strip_prefix("/2024/day/")
+                .and_then(|rest| rest.strip_suffix("/input"))
+                .filter(|day| *day != "25");
+            match (req.cookie.as_deref(), day) {
+                (Some("session=secret"), Some(day)) => (200, format!("input {}\n", day)),
+                (Some("session=secret"), None) => (404, "nope".to_string()),
+                _ => (400, "log in".to_string()),
+            }
+        })
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn inputs(root: &Path, server: &MockServer, session: &str) -> Inputs<Ureq> {
+        let mut inputs = Inputs::new(
+            root,
+            &server.base_url,
+            Some(session.to_string()),
+            Ureq::default(),
+        );
+        inputs.throttle.interval = Duration::ZERO;
+        inputs
+    }
+
+    #[test]
+    fn test_fetches_once_then_uses_cache() {
+        let server = server();
+        let root = scratch_dir("cache");
+        let inputs = inputs(&root, &server, "secret");
+
+        let path = inputs.resolve(3).unwrap();
+        assert_eq!(root.join("day-03/data/day-03.txt"), path);
+        assert_eq!("input 3\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(1, server.hits());
+
+        inputs.resolve(3).unwrap();
+        assert_eq!(1, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_never_refetches_existing_input() {
+        let server = server();
+        let root = scratch_dir("existing");
+        fs::create_dir_all(root.join("day-01/data")).unwrap();
+        fs::write(root.join("day-01/data/day-01.txt"), "mine\n").unwrap();
+        // an empty file is only a placeholder from `aoc new`
+        fs::create_dir_all(root.join("day-02/data")).unwrap();
+        fs::write(root.join("day-02/data/day-02.txt"), "").unwrap();
+
+        let inputs = inputs(&root, &server, "secret");
+        inputs.resolve(1).unwrap();
+        assert_eq!(0, server.hits());
+        assert_eq!(
+            "mine\n",
+            fs::read_to_string(root.join("day-01/data/day-01.txt")).unwrap()
+        );
+        inputs.resolve(2).unwrap();
+        assert_eq!(1, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_failed_fetch_writes_nothing() {
+        let server = server();
+        let root = scratch_dir("failed");
+
+        let err = inputs(&root, &server, "secret").resolve(25).unwrap_err();
+        assert!(matches!(&err, AocError::Fetch { message, .. } if message.contains("unlocked")));
+        let err = inputs(&root, &server, "wrong").resolve(4).unwrap_err();
+        assert!(matches!(&err, AocError::Fetch { message, .. } if message.contains("400")));
+
+        assert!(!root.join("day-25/data/day-25.txt").exists());
+        assert!(!root.join("day-04/data/day-04.txt").exists());
+        assert_eq!(2, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_needs_a_session() {
+        let server = server();
+        let root = scratch_dir("session");
+        let inputs = Inputs::new(&root, &server.base_url, None, Ureq::default());
+        assert!(matches!(inputs.resolve(5), Err(AocError::Usage(_))));
+        assert_eq!(0, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let server = server();
+        let root = scratch_dir("rate");
+        let interval = Duration::from_millis(200);
+        let mut inputs = inputs(&root, &server, "secret");
+        inputs.throttle.interval = interval;
+
+        let start = Instant::now();
+        inputs.resolve(6).unwrap();
+        inputs.resolve(7).unwrap();
+        assert!(start.elapsed() >= interval);
+        assert_eq!(2, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
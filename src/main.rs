@@ -1,3 +1,664 @@
-fn main() {
-    println!("Hello, world!");
+mod answers;
+mod bench;
+mod diagnose;
+mod export;
+mod http;
+mod inputs;
+mod memory;
+mod reconcile;
+mod registry;
+mod scaffold;
+mod submit;
+mod viz;
+mod watch;
+
+use std::{
+    fs::read_to_string,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use answers::{Answers, Status};
+use aoc_common::{viz::Palette, AocError, Format, Report, Solution, Solver};
+use clap::{Args, Parser, Subcommand};
+use day_01::reconcile::{Delimiter, ListFormat, Reconciliation};
+use day_02::dampener::Dampener;
+use day_03::lexer::PUZZLE_DIGITS;
+use inputs::Inputs;
+use submit::{Submitter, Verdict};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day/part, every part of a day, or everything with --all
+    Run(RunArgs),
+    /// Check every solver against the answers recorded in answers.toml
+    Verify(VerifyArgs),
+    /// Create a day-NN crate with both parts stubbed out
+    New(NewArgs),
+    /// Download a day's input into day-XX/data unless it is already there
+    Fetch(FetchArgs),
+    /// Solve a day/part and post the answer, unless earlier submissions
+    /// already settle it
+    Submit(SubmitArgs),
+    /// Re-run a day's example tests and solver whenever its sources or data
+    /// change
+    Watch(WatchArgs),
+    /// Play a day's simulation in the terminal, or dump its frames to text
+    /// files with --dump
+    Viz(VizArgs),
+    /// Print a random input for a day, the same one every time for a seed
+    Gen(GenArgs),
+    /// Stream day 1's location lists and break down where the distance and
+    /// similarity totals come from
+    Reconcile(ReconcileArgs),
+    /// Say why each of day 2's reports is safe or not, with the levels
+    /// that decide it highlighted
+    Diagnose(DiagnoseArgs),
+    /// Add up day 3's instructions for both parts, optionally listing each
+    /// one and where it is in the memory
+    Memory(MemoryArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Runs every part of the day when omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Defaults to day-XX/data/day-XX.txt, fetched with AOC_SESSION if missing
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Run against day-XX/data/examples/NAME.txt instead of the real input
+    #[arg(long, value_name = "NAME", requires = "day", conflicts_with = "input")]
+    example: Option<String>,
+    #[arg(long, conflicts_with_all = ["day", "part", "input", "example"])]
+    all: bool,
+    /// Print answers as text or as one JSON object per line
+    #[arg(
+        long,
+        default_value = "text",
+        value_name = "text|json",
+        conflicts_with = "bench"
+    )]
+    format: Format,
+    /// Time the parse and solve steps over N runs instead of printing answers
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+    /// Write the benchmark results to a JSON file
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    save_baseline: Option<PathBuf>,
+    /// Compare the benchmark results against a file from --save-baseline
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    baseline: Option<PathBuf>,
+    /// Size of the thread pool for builds with the parallel feature
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    threads: Option<u32>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(long)]
+    day: Option<u8>,
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Parsing crates to depend on, e.g. --dep nom,itertools
+    #[arg(long = "dep", value_enum, value_delimiter = ',')]
+    deps: Vec<scaffold::Dep>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    day: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Submit this instead of solving the puzzle
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[arg(long)]
+    day: u8,
+    /// Watches every part of the day when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Defaults to day-XX/data/day-XX.txt, fetched with AOC_SESSION if missing
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct VizArgs {
+    #[arg(long)]
+    day: u8,
+    /// Defaults to the first part of the day with a simulation
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Defaults to day-XX/data/day-XX.txt, fetched with AOC_SESSION if missing
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use day-XX/data/examples/NAME.txt instead of the real input
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    example: Option<String>,
+    /// Write the frames to DIR/frame-NNNN.txt instead of playing them
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["png", "gif"])]
+    dump: Option<PathBuf>,
+    /// Write the frames to DIR/frame-NNNN.png instead of playing them
+    #[arg(long, value_name = "DIR", conflicts_with = "gif")]
+    png: Option<PathBuf>,
+    /// Write the frames to FILE as an animated GIF instead of playing them
+    #[arg(long, value_name = "FILE")]
+    gif: Option<PathBuf>,
+    /// Pixels per cell side in PNG and GIF images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+    /// Image colours per cell character, e.g. '#=808080,O=c0a000'; '*' sets
+    /// the colour of every other character
+    #[arg(long, value_name = "SPEC", default_value = "")]
+    palette: Palette,
+    /// Frames per second to play at, or to animate the GIF at
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000))]
+    fps: u32,
+    /// Only the N frames most likely to hold the answer, best first, for
+    /// simulations that can rank them (day 14)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    candidates: Option<u32>,
+    /// Leave out the first N frames
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    skip: usize,
+    /// Stop after N frames
+    #[arg(long, value_name = "N")]
+    limit: Option<usize>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    #[arg(long)]
+    day: u8,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Lines, machines or robots to write, or the side of a grid
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+    /// Write the input to FILE instead of stdout
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ReconcileArgs {
+    /// Defaults to day-01/data/day-01.txt, fetched with AOC_SESSION if missing
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// How many of the furthest pairs and biggest similarity contributions
+    /// to list
+    #[arg(long, value_name = "K", default_value_t = 5)]
+    top: usize,
+    /// List every pair and its distance first, smallest IDs first
+    #[arg(long)]
+    pairs: bool,
+    /// The two lists to pair up and list the top K of, by header name or by
+    /// number from 1
+    #[arg(long, value_name = "A,B", default_value = "1,2")]
+    compare: String,
+    /// What separates the columns: whitespace, comma or tab. Worked out
+    /// from the first row when omitted
+    #[arg(long)]
+    delimiter: Option<Delimiter>,
+    /// The first row names the columns, even if some of them are numbers
+    #[arg(long, conflicts_with = "no_header")]
+    header: bool,
+    /// The first row is data, even if it does not look like it
+    #[arg(long)]
+    no_header: bool,
+}
+
+#[derive(Args)]
+struct DiagnoseArgs {
+    /// Defaults to day-02/data/day-02.txt, fetched with AOC_SESSION if missing
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use day-02/data/examples/NAME.txt instead of the real input
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    example: Option<String>,
+    /// How many levels the dampener may remove from a report
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    removals: usize,
+    /// The allowed steps between neighbouring levels
+    #[arg(long, value_name = "LOW-HIGH", default_value = "1-3", value_parser = diagnose::parse_steps)]
+    steps: RangeInclusive<u64>,
+    /// Only print the counts, not every report
+    #[arg(long)]
+    summary: bool,
+}
+
+#[derive(Args)]
+struct MemoryArgs {
+    /// Defaults to day-03/data/day-03.txt, fetched with AOC_SESSION if missing
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use day-03/data/examples/NAME.txt instead of the real input
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    example: Option<String>,
+    /// The most digits a `mul` operand may have
+    #[arg(long, value_name = "N", default_value_t = PUZZLE_DIGITS)]
+    max_digits: usize,
+    /// Read operands of any length that fits in a u32
+    #[arg(long, conflicts_with = "max_digits")]
+    no_digit_limit: bool,
+    /// List every instruction with its byte range, and whether part 2 has
+    /// it enabled
+    #[arg(long)]
+    tokens: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::New(args) => new_day(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Watch(args) => watch(args),
+        Command::Viz(args) => viz(args),
+        Command::Gen(args) => gen(args),
+        Command::Reconcile(args) => reconcile(args),
+        Command::Diagnose(args) => diagnose(args),
+        Command::Memory(args) => memory(args),
+    };
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            if let Some(diagnostic) = err.diagnostic() {
+                eprintln!("{}", diagnostic);
+            }
+            err.exit_code()
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<ExitCode, AocError> {
+    if let Some(threads) = args.threads {
+        aoc_common::par::set_threads(threads as usize)?;
+    }
+    if let Some(runs) = args.bench {
+        return run_bench(&args, runs as usize);
+    }
+    if args.all {
+        return Ok(run_all(args.format));
+    }
+    for solver in selected(&args)? {
+        let path = input_path(solver, args.input.clone(), args.example.as_deref())?;
+        let timed = solver.run_timed(&read_input(&path)?)?;
+        Report::new(solver.day, solver.part, &timed, &path).print(args.format);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_bench(args: &RunArgs, runs: usize) -> Result<ExitCode, AocError> {
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(bench::load_baseline)
+        .transpose()?;
+    bench::print_header(baseline.is_some());
+    let mut results = vec![];
+    for solver in selected(args)? {
+        let input = read_input(&input_path(
+            solver,
+            args.input.clone(),
+            args.example.as_deref(),
+        )?)?;
+        let result = bench::bench(solver, &input, runs)?;
+        bench::print_row(&result, baseline.as_deref());
+        results.push(result);
+    }
+    if let Some(path) = &args.save_baseline {
+        bench::save_baseline(path, &results)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The solvers picked out by --day/--part, or all of them with --all.
+fn selected(args: &RunArgs) -> Result<Vec<&'static Solver>, AocError> {
+    if args.all {
+        return Ok(registry::SOLVERS.iter().collect());
+    }
+    let day = args.day.expect("clap enforces --day without --all");
+    let solvers: Vec<&Solver> = match args.part {
+        Some(part) => vec![registry::find(day, part).ok_or_else(|| unknown(day, part))?],
+        None => registry::for_day(day).collect(),
+    };
+    if solvers.is_empty() {
+        return Err(AocError::Usage(format!("day {} is not registered", day)));
+    }
+    Ok(solvers)
+}
+
+/// `input` if given, else the example called `example`, else the puzzle
+/// input for the solver's day.
+fn input_path(
+    solver: &Solver,
+    input: Option<PathBuf>,
+    example: Option<&str>,
+) -> Result<PathBuf, AocError> {
+    match (input, example) {
+        (Some(input), _) => Ok(input),
+        (None, Some(name)) => Ok(PathBuf::from(solver.example_input(name))),
+        (None, None) => Inputs::from_env(Path::new("")).resolve(solver.day),
+    }
+}
+
+fn run_all(format: Format) -> ExitCode {
+    let mut failed = 0;
+    if format == Format::Text {
+        println!("{:>3} {:>4} {:>20} {:>10}", "day", "part", "answer", "time");
+    }
+    for solver in registry::SOLVERS {
+        let path = PathBuf::from(solver.default_input());
+        let start = Instant::now();
+        let result = read_input(&path).and_then(|input| solver.run_timed(&input));
+        let elapsed = start.elapsed();
+        match (format, result) {
+            (Format::Json, Ok(timed)) => {
+                println!(
+                    "{}",
+                    Report::new(solver.day, solver.part, &timed, &path).to_json()
+                );
+            }
+            (Format::Json, Err(err)) => {
+                failed += 1;
+                eprintln!("error: day {} part {}: {}", solver.day, solver.part, err);
+            }
+            (Format::Text, result) => {
+                let answer = result.map(|timed| timed.answer).unwrap_or_else(|err| {
+                    failed += 1;
+                    format!("error: {}", err)
+                });
+                println!(
+                    "{:>3} {:>4} {:>20} {:>8.2}ms",
+                    solver.day,
+                    solver.part,
+                    answer,
+                    elapsed.as_secs_f64() * 1000.0
+                );
+            }
+        }
+    }
+    if format == Format::Text {
+        println!(
+            "{} of {} solutions ran successfully",
+            registry::SOLVERS.len() - failed,
+            registry::SOLVERS.len()
+        );
+    }
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<ExitCode, AocError> {
+    let answers = Answers::load(&args.answers)?;
+    let solvers: Vec<&Solver> = registry::SOLVERS
+        .iter()
+        .filter(|s| args.day.is_none_or(|day| s.day == day))
+        .collect();
+    if solvers.is_empty() {
+        return Err(AocError::Usage(format!(
+            "day {} is not registered",
+            args.day.unwrap_or_default()
+        )));
+    }
+
+    let mut failed = 0;
+    let mut verified = 0;
+    println!(
+        "{:>3} {:>4} {:>20} {:>20}  status",
+        "day", "part", "expected", "answer"
+    );
+    for solver in &solvers {
+        let verification = answers.verify(solver, Path::new(""));
+        let status = match verification.status {
+            Status::Ok => {
+                verified += 1;
+                "ok".to_string()
+            }
+            Status::Unrecorded => "no recorded answer".to_string(),
+            Status::Mismatch => {
+                failed += 1;
+                "MISMATCH".to_string()
+            }
+            Status::Error(err) => {
+                failed += 1;
+                format!("error: {}", err)
+            }
+        };
+        println!(
+            "{:>3} {:>4} {:>20} {:>20}  {}",
+            solver.day,
+            solver.part,
+            answers.get(solver.day, solver.part).unwrap_or("-"),
+            verification.answer,
+            status
+        );
+    }
+    println!("{} of {} answers verified", verified, solvers.len());
+    if failed == 0 {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn new_day(args: NewArgs) -> Result<ExitCode, AocError> {
+    let dir = scaffold::new_day(Path::new("."), args.day, &args.deps)?;
+    println!("created {}", dir.display());
+    println!(
+        "once it solves, add day-{:02} to Cargo.toml, the solvers and generators in src/registry.rs, and answers.toml",
+        args.day
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fetch(args: FetchArgs) -> Result<ExitCode, AocError> {
+    let inputs = Inputs::from_env(Path::new(""));
+    if inputs.is_cached(args.day) {
+        println!("{} is already cached", inputs.path(args.day).display());
+    } else {
+        println!("fetched {}", inputs.resolve(args.day)?.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(args: SubmitArgs) -> Result<ExitCode, AocError> {
+    let root = Path::new("");
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let solver =
+                registry::find(args.day, args.part).ok_or_else(|| unknown(args.day, args.part))?;
+            let path = Inputs::from_env(root).resolve(args.day)?;
+            solver.run(&read_input(&path)?)?
+        }
+    };
+    let outcome = Submitter::from_env(root).submit(args.day, args.part, &answer)?;
+    let source = if outcome.local {
+        " (from earlier submissions, not sent)"
+    } else {
+        ""
+    };
+    println!(
+        "day {} part {}: {} is {}{}",
+        args.day, args.part, answer, outcome.verdict, source
+    );
+    Ok(match outcome.verdict {
+        Verdict::Correct | Verdict::AlreadySolved => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+fn watch(args: WatchArgs) -> Result<ExitCode, AocError> {
+    // goes through cargo rather than the registry, so days that are not
+    // registered yet can be watched too
+    let bin = |part: u8| PathBuf::from(format!("day-{:02}/src/bin/part{}.rs", args.day, part));
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => (1..=2).filter(|part| bin(*part).exists()).collect(),
+    };
+    if parts.is_empty() || !parts.iter().all(|part| bin(*part).exists()) {
+        return Err(AocError::Usage(format!(
+            "day-{:02} has no matching part binaries",
+            args.day
+        )));
+    }
+    let input = match args.input {
+        Some(input) => input,
+        None => Inputs::from_env(Path::new("")).resolve(args.day)?,
+    };
+    watch::Watch::new(args.day, parts, input).run()?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn viz(args: VizArgs) -> Result<ExitCode, AocError> {
+    let sim = viz::find(args.day, args.part).ok_or_else(|| {
+        AocError::Usage(match args.part {
+            Some(part) => format!("day {} part {} has no simulation", args.day, part),
+            None => format!("day {} has no simulation", args.day),
+        })
+    })?;
+    let path = match (args.input, &args.example) {
+        (Some(input), _) => input,
+        (None, Some(name)) => {
+            PathBuf::from(format!("day-{:02}/data/examples/{}.txt", sim.day, name))
+        }
+        (None, None) => Inputs::from_env(Path::new("")).resolve(sim.day)?,
+    };
+    let input = read_input(&path)?;
+    let frames = match (args.candidates, sim.candidates) {
+        (None, _) => (sim.frames)(&input)?,
+        (Some(count), Some(candidates)) => candidates(&input, count as usize)?,
+        (Some(_), None) => {
+            return Err(AocError::Usage(format!(
+                "day {} part {} cannot rank its frames",
+                sim.day, sim.part
+            )))
+        }
+    };
+    let frames = frames
+        .skip(args.skip)
+        .take(args.limit.unwrap_or(usize::MAX));
+    let written = match (args.dump, args.png, args.gif) {
+        (Some(dir), _, _) => Some((aoc_common::viz::dump(frames, &dir)?, dir)),
+        (_, Some(dir), _) => Some((
+            export::write_pngs(frames, &dir, &args.palette, args.scale)?,
+            dir,
+        )),
+        (_, _, Some(file)) => Some((
+            export::write_gif(frames, &file, &args.palette, args.scale, args.fps)?,
+            file,
+        )),
+        (None, None, None) => {
+            viz::Player::new(args.fps).play(Box::new(frames))?;
+            None
+        }
+    };
+    if let Some((count, path)) = written {
+        println!("wrote {} frames to {}", count, path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn gen(args: GenArgs) -> Result<ExitCode, AocError> {
+    let generator = registry::generator(args.day)
+        .ok_or_else(|| AocError::Usage(format!("day {} has no input generator", args.day)))?;
+    let input = aoc_common::gen::generate(generator, args.seed, args.size as usize);
+    match args.output {
+        Some(path) => inputs::write_atomic(&path, &input)?,
+        None => print!("{}", input),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn reconcile(args: ReconcileArgs) -> Result<ExitCode, AocError> {
+    let path = match args.input {
+        Some(input) => input,
+        None => Inputs::from_env(Path::new("")).resolve(1)?,
+    };
+    let format = ListFormat {
+        delimiter: args.delimiter,
+        header: match (args.header, args.no_header) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        },
+    };
+    let lists = Reconciliation::read(&path, format)?;
+    let (a, b) = reconcile::compared(&lists, &args.compare)?;
+    reconcile::print(&lists, a, b, args.top, args.pairs);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn diagnose(args: DiagnoseArgs) -> Result<ExitCode, AocError> {
+    let solver = registry::find(2, 1).expect("day 2 is registered");
+    let path = input_path(solver, args.input, args.example.as_deref())?;
+    let reports = day_02::part1::Part1::parse(&read_input(&path)?)?;
+    let dampener = Dampener::new(args.removals, args.steps);
+    diagnose::print(&reports, &dampener, args.summary);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn memory(args: MemoryArgs) -> Result<ExitCode, AocError> {
+    let solver = registry::find(3, 1).expect("day 3 is registered");
+    let path = input_path(solver, args.input, args.example.as_deref())?;
+    let max_digits = (!args.no_digit_limit).then_some(args.max_digits);
+    let run = memory::run(&read_input(&path)?, max_digits);
+    if args.tokens {
+        for line in &run.listing {
+            println!("{}", line);
+        }
+        println!();
+    }
+    println!("part 1: {}", run.part1);
+    println!("part 2: {}", run.part2);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_input(path: &Path) -> Result<String, AocError> {
+    read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn unknown(day: u8, part: u8) -> AocError {
+    AocError::Usage(format!("day {} part {} is not registered", day, part))
 }
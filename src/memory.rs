@@ -0,0 +1,72 @@
+use day_03::{
+    interpreter::Interpreter,
+    lexer::{Lexer, Spanned, Token},
+};
+
+/// Both parts' totals for a corrupted memory, and each instruction in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// Each instruction's byte range and text, with the `mul`s that part 2
+    /// skips marked.
+    pub listing: Vec<String>,
+    pub part1: u128,
+    pub part2: u128,
+}
+
+/// Reads `memory` with operands of up to `max_digits` digits, or any length
+/// for `None`.
+pub fn run(memory: &str, max_digits: Option<usize>) -> Run {
+    let mut part1 = Interpreter::new(false);
+    let mut part2 = Interpreter::new(true);
+    let mut listing = vec![];
+    for Spanned { token, span } in Lexer::new(memory).max_digits(max_digits) {
+        // do() and don't() are never skipped themselves, they only switch
+        // the muls after them
+        let skipped = matches!(token, Token::Mul(..)) && !part2.enabled();
+        let note = if skipped { "  (disabled)" } else { "" };
+        listing.push(format!("{:?}  {}{}", span, &memory[span.clone()], note));
+        part1.step(token);
+        part2.step(token);
+    }
+    Run {
+        listing,
+        part1: part1.total(),
+        part2: part2.total(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use day_03::lexer::PUZZLE_DIGITS;
+
+    use super::*;
+
+    #[test]
+    fn test_listing() {
+        let memory = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(
+            Run {
+                listing: vec![
+                    "1..9  mul(2,4)".to_string(),
+                    "20..27  don't()".to_string(),
+                    "28..36  mul(5,5)  (disabled)".to_string(),
+                    "48..57  mul(11,8)  (disabled)".to_string(),
+                    "59..63  do()".to_string(),
+                    "64..72  mul(8,5)".to_string(),
+                ],
+                part1: 161,
+                part2: 48,
+            },
+            run(memory, Some(PUZZLE_DIGITS))
+        );
+    }
+
+    #[test]
+    fn test_digit_limit() {
+        let memory = "mul(1000,2)don't()do()mul(3,4)";
+        assert_eq!(12, run(memory, Some(PUZZLE_DIGITS)).part1);
+        let unlimited = run(memory, None);
+        assert_eq!((2012, 2012), (unlimited.part1, unlimited.part2));
+        assert_eq!("0..11  mul(1000,2)", unlimited.listing[0]);
+    }
+}
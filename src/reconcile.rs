@@ -0,0 +1,126 @@
+use std::fmt::{Display, Write};
+
+use aoc_common::AocError;
+use day_01::reconcile::Reconciliation;
+
+/// The two lists `--compare A,B` picks out, by header name or by number
+/// from 1.
+pub fn compared(lists: &Reconciliation, compare: &str) -> Result<(usize, usize), AocError> {
+    let column = |name: &str| {
+        lists
+            .column(name.trim())
+            .ok_or_else(|| AocError::Usage(format!("there is no list called '{}'", name)))
+    };
+    match compare.split_once(',') {
+        Some((a, b)) => Ok((column(a)?, column(b)?)),
+        None => Err(AocError::Usage(
+            "--compare takes two lists, A,B".to_string(),
+        )),
+    }
+}
+
+/// Prints where the distance and similarity between lists `a` and `b` come
+/// from, after every pair if `pairs` is set and with the matrices of every
+/// two lists when there are more than two.
+pub fn print(lists: &Reconciliation, a: usize, b: usize, top: usize, pairs: bool) {
+    let names = lists.names();
+    if pairs {
+        for pair in lists.pairs(a, b) {
+            println!("{}\t{}\t{}", pair.left, pair.right, pair.distance);
+        }
+        println!();
+    }
+    println!("{} rows of {} lists", lists.len(), lists.columns());
+    if lists.columns() > 2 {
+        println!("distance:");
+        print!("{}", matrix(names, &lists.distance_matrix()));
+        println!("similarity:");
+        print!("{}", matrix(names, &lists.similarity_matrix()));
+    }
+    println!(
+        "distance between {} and {}: {}",
+        names[a],
+        names[b],
+        lists.distance(a, b)
+    );
+    println!(
+        "similarity between {} and {}: {}",
+        names[a],
+        names[b],
+        lists.similarity(a, b)
+    );
+    if top > 0 {
+        println!("furthest pairs:");
+        for pair in lists.top_distances(a, b, top) {
+            println!("  |{} - {}| = {}", pair.left, pair.right, pair.distance);
+        }
+        println!("biggest similarity contributions:");
+        for c in lists.top_contributions(a, b, top) {
+            println!(
+                "  {} x {} in {} x {} in {} = {}",
+                c.id, c.left, names[a], c.right, names[b], c.score
+            );
+        }
+    }
+}
+
+/// `rows` as a table with the list names along both edges.
+fn matrix<T: Display>(names: &[String], rows: &[Vec<T>]) -> String {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect();
+    let width = names
+        .iter()
+        .map(|name| name.len())
+        .chain(cells.iter().flatten().map(|cell| cell.len()))
+        .max()
+        .unwrap_or(0);
+    let label = names.iter().map(|name| name.len()).max().unwrap_or(0);
+    let mut out = format!("  {:label$}", "");
+    for name in names {
+        write!(out, "  {:>width$}", name).unwrap();
+    }
+    out.push('\n');
+    for (name, row) in names.iter().zip(&cells) {
+        write!(out, "  {:label$}", name).unwrap();
+        for cell in row {
+            write!(out, "  {:>width$}", cell).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use day_01::reconcile::ListFormat;
+
+    use super::*;
+
+    fn lists() -> Reconciliation {
+        let input = "north,south,east\n3,4,1\n4,3,4\n2,5,4\n";
+        Reconciliation::parse_with(input, ListFormat::default()).unwrap()
+    }
+
+    #[test]
+    fn test_compared() {
+        let lists = lists();
+        assert_eq!((0, 2), compared(&lists, "north, east").unwrap());
+        assert_eq!((1, 0), compared(&lists, "2,1").unwrap());
+        assert!(matches!(
+            compared(&lists, "north,west"),
+            Err(AocError::Usage(_))
+        ));
+        assert!(matches!(compared(&lists, "north"), Err(AocError::Usage(_))));
+    }
+
+    #[test]
+    fn test_matrix() {
+        let lists = lists();
+        assert_eq!(
+            "         north  south   east\n  north      0      3      2\n  south      3      0      3\n  east       2      3      0\n",
+            matrix(lists.names(), &lists.distance_matrix())
+        );
+    }
+}
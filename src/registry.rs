@@ -0,0 +1,138 @@
+use aoc_common::{gen::Generator, Solver};
+
+/// Every solution the `aoc` binary knows how to run, in day/part order.
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day_01::part1::Part1>(1, 1),
+    Solver::new::<day_01::part2::Part2>(1, 2),
+    Solver::new::<day_02::part1::Part1>(2, 1),
+    Solver::new::<day_02::part2::Part2>(2, 2),
+    Solver::new::<day_03::part1::Part1>(3, 1),
+    Solver::new::<day_03::part2::Part2>(3, 2),
+    Solver::new::<day_04::part1::Part1>(4, 1),
+    Solver::new::<day_04::part2::Part2>(4, 2),
+    Solver::new::<day_05::part1::Part1>(5, 1),
+    Solver::new::<day_06::part1::Part1>(6, 1),
+    Solver::new::<day_06::part2::Part2>(6, 2),
+    Solver::new::<day_07::part1::Part1>(7, 1),
+    Solver::new::<day_07::part2::Part2>(7, 2),
+    Solver::new::<day_08::part1::Part1>(8, 1),
+    Solver::new::<day_08::part2::Part2>(8, 2),
+    Solver::new::<day_09::part1::Part1>(9, 1),
+    Solver::new::<day_09::part2::Part2>(9, 2),
+    Solver::new::<day_10::part1::Part1>(10, 1),
+    Solver::new::<day_10::part2::Part2>(10, 2),
+    Solver::new::<day_11::part1::Part1>(11, 1),
+    Solver::new::<day_11::part2::Part2>(11, 2),
+    Solver::new::<day_12::part1::Part1>(12, 1),
+    Solver::new::<day_12::part2::Part2>(12, 2),
+    Solver::new::<day_13::part1::Part1>(13, 1),
+    Solver::new::<day_13::part2::Part2>(13, 2),
+    Solver::new::<day_14::part1::Part1>(14, 1),
+    Solver::new::<day_14::part2::Part2>(14, 2),
+    Solver::new::<day_15::part1::Part1>(15, 1),
+    Solver::new::<day_15::part2::Part2>(15, 2),
+];
+
+/// The random input generator of every day, in day order.
+pub const GENERATORS: &[(u8, Generator)] = &[
+    (1, day_01::gen::input),
+    (2, day_02::gen::input),
+    (3, day_03::gen::input),
+    (4, day_04::gen::input),
+    (5, day_05::gen::input),
+    (6, day_06::gen::input),
+    (7, day_07::gen::input),
+    (8, day_08::gen::input),
+    (9, day_09::gen::input),
+    (10, day_10::gen::input),
+    (11, day_11::gen::input),
+    (12, day_12::gen::input),
+    (13, day_13::gen::input),
+    (14, day_14::gen::input),
+    (15, day_15::gen::input),
+];
+
+pub fn generator(day: u8) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, generator)| *generator)
+}
+
+pub fn find(day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn for_day(day: u8) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |s| s.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        gen::generate,
+        par::{self, Mode},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_registry_is_sorted_and_unique() {
+        let keys: Vec<_> = SOLVERS.iter().map(|s| (s.day, s.part)).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(12, 2).is_some());
+        assert!(find(5, 2).is_none());
+        assert_eq!(2, for_day(1).count());
+    }
+
+    #[test]
+    fn test_every_day_has_a_generator() {
+        let days: Vec<_> = GENERATORS.iter().map(|(day, _)| *day).collect();
+        let mut solver_days: Vec<_> = SOLVERS.iter().map(|s| s.day).collect();
+        solver_days.dedup();
+        assert_eq!(solver_days, days);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for (day, generator) in GENERATORS {
+            for seed in 0..5 {
+                let input = generate(*generator, seed, 12);
+                for solver in for_day(*day) {
+                    if let Err(err) = solver.run(&input) {
+                        panic!(
+                            "day {} part {} with seed {}: {}",
+                            day, solver.part, seed, err
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Only tells the modes apart in a build with the parallel feature,
+    /// where `Mode::Parallel` really uses the thread pool.
+    #[test]
+    fn test_parallel_matches_sequential() {
+        for solver in SOLVERS {
+            let generator = generator(solver.day).unwrap();
+            for seed in 0..3 {
+                let input = generate(generator, seed, 20);
+                par::set_mode(Mode::Sequential);
+                let sequential = solver.run(&input).map_err(|err| err.to_string());
+                par::set_mode(Mode::Parallel);
+                let parallel = solver.run(&input).map_err(|err| err.to_string());
+                assert_eq!(
+                    sequential, parallel,
+                    "day {} part {} with seed {}",
+                    solver.day, solver.part, seed
+                );
+            }
+        }
+        par::set_mode(Mode::DEFAULT);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::AocError;
+use clap::ValueEnum;
+
+/// Optional parsing dependencies for a new day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Dep {
+    Nom,
+    Itertools,
+}
+
+impl Dep {
+    fn manifest_line(self) -> &'static str {
+        match self {
+            Dep::Nom => "nom = \"7.1.3\"\n",
+            Dep::Itertools => "itertools = \"0.13.0\"\n",
+        }
+    }
+}
+
+/// Creates `day-NN/` under `root` with both parts stubbed out, and returns
+/// the directory it made. Refuses to touch a day that already exists.
+pub fn new_day(root: &Path, day: u8, deps: &[Dep]) -> Result<PathBuf, AocError> {
+    let name = format!("day-{:02}", day);
+    let dir = root.join(&name);
+    // create_dir fails if the directory exists, so a day is never overwritten
+    fs::create_dir(&dir).map_err(|source| match source.kind() {
+        std::io::ErrorKind::AlreadyExists => {
+            AocError::Usage(format!("{} already exists, refusing to overwrite it", name))
+        }
+        _ => AocError::Io {
+            path: dir.clone(),
+            source,
+        },
+    })?;
+
+    let nom = deps.contains(&Dep::Nom);
+    let mut manifest = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\naoc-common.workspace = true\n",
+        name
+    );
+    for dep in [Dep::Itertools, Dep::Nom] {
+        if deps.contains(&dep) {
+            manifest.push_str(dep.manifest_line());
+        }
+    }
+    manifest.push_str("\n[build-dependencies]\naoc-common.workspace = true\n");
+
+    let crate_name = name.replace('-', "_");
+    let mut files = vec![
+        ("Cargo.toml".to_string(), manifest),
+        ("build.rs".to_string(), BUILD_RS.to_string()),
+        ("src/lib.rs".to_string(), LIB_RS.to_string()),
+        ("src/gen.rs".to_string(), GEN_RS.to_string()),
+        (format!("data/{}.txt", name), String::new()),
+        ("data/examples/sample.txt".to_string(), String::new()),
+        (
+            "data/examples/sample.answers".to_string(),
+            SAMPLE_ANSWERS.to_string(),
+        ),
+    ];
+    for part in [1, 2] {
+        files.push((format!("src/part{}.rs", part), part_rs(part, nom)));
+        files.push((
+            format!("src/bin/part{}.rs", part),
+            format!(
+                "use std::process::ExitCode;\n\nfn main() -> ExitCode {{\n    aoc_common::run::<{}::part{}::Part{}>({}, {})\n}}\n",
+                crate_name, part, part, day, part
+            ),
+        ));
+    }
+
+    for (file, contents) in files {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|source| AocError::Io {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(&path, contents).map_err(|source| AocError::Io { path, source })?;
+    }
+    Ok(dir)
+}
+
+fn part_rs(part: u8, nom: bool) -> String {
+    let template = if nom { PART_NOM_RS } else { PART_RS };
+    template.replace("{part}", &part.to_string())
+}
+
+const BUILD_RS: &str = "fn main() {
+    aoc_common::examples::generate_tests();
+}
+";
+
+const LIB_RS: &str = "pub mod gen;
+pub mod part1;
+pub mod part2;
+
+aoc_common::example_tests!();
+";
+
+const GEN_RS: &str = "use aoc_common::gen::{GenRng, Rng};
+
+/// A random input of about `size` lines.
+pub fn input(rng: &mut GenRng, size: usize) -> String {
+    todo!(\"write {} random lines, e.g. {}\", size, rng.gen_range(0..10))
+}
+";
+
+const SAMPLE_ANSWERS: &str =
+    "# paste the example from the puzzle into sample.txt and its answers here
+part1 = TODO
+";
+
+const PART_RS: &str = "use aoc_common::{AocError, Solution};
+
+pub struct Part{part};
+
+impl Solution for Part{part} {
+    type Input = Vec<String>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        todo!(\"solve part {part} for {} lines\", input.len())
+    }
+}
+";
+
+const PART_NOM_RS: &str = "use aoc_common::{parse_complete, AocError, Solution};
+use nom::{
+    character::complete::{newline, not_line_ending},
+    combinator::map,
+    multi::separated_list1,
+    IResult,
+};
+
+pub struct Part{part};
+
+impl Solution for Part{part} {
+    type Input = Vec<String>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_complete(input, parse_input)
+    }
+
+    fn solve(input: &Self::Input) -> Self::Output {
+        todo!(\"solve part {part} for {} lines\", input.len())
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<String>> {
+    separated_list1(newline, map(not_line_ending, String::from))(input)
+}
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-new-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = scratch_dir("layout");
+        let dir = new_day(&root, 16, &[Dep::Nom]).unwrap();
+        let read = |file: &str| fs::read_to_string(dir.join(file)).unwrap();
+
+        assert!(read("Cargo.toml").contains("name = \"day-16\""));
+        assert!(read("Cargo.toml").contains("nom = "));
+        assert!(!read("Cargo.toml").contains("itertools"));
+        assert!(read("src/bin/part2.rs").contains("run::<day_16::part2::Part2>(16, 2)"));
+        assert!(read("src/part1.rs").contains("parse_complete(input, parse_input)"));
+        assert!(read("src/part2.rs").contains("pub struct Part2;"));
+        assert!(read("src/lib.rs").contains("pub mod gen;"));
+        assert!(read("src/gen.rs").contains("pub fn input(rng: &mut GenRng, size: usize)"));
+        assert_eq!("", read("data/day-16.txt"));
+        assert!(read("data/examples/sample.answers").contains("part1 = TODO"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = scratch_dir("exists");
+        fs::create_dir(root.join("day-03")).unwrap();
+        fs::write(root.join("day-03/keep.txt"), "mine").unwrap();
+        assert!(matches!(new_day(&root, 3, &[]), Err(AocError::Usage(_))));
+        assert_eq!(
+            "mine",
+            fs::read_to_string(root.join("day-03/keep.txt")).unwrap()
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
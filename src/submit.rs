@@ -0,0 +1,515 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::AocError;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::{self, Http, Throttle, Ureq},
+    inputs::write_atomic,
+};
+
+const HISTORY_FILE: &str = ".aoc-submissions.json";
+/// How long the server makes you wait after a wrong answer.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// What the server made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in either direction
+    Incorrect,
+    /// Submitted too soon after the last answer
+    Wait(Duration),
+    /// This part was already solved, so the answer was not checked
+    AlreadySolved,
+    /// The server would not check this level, either because it was already
+    /// solved or because it is still locked behind the part before it
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait(wait) => {
+                let secs = wait.as_secs();
+                if secs >= 60 {
+                    write!(f, "wait {}m {}s", secs / 60, secs % 60)
+                } else {
+                    write!(f, "wait {}s", secs)
+                }
+            }
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::WrongLevel => write!(f, "not the level being solved"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the server sends back.
+pub fn parse_response(page: &str) -> Option<Verdict> {
+    if page.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    } else if page.contains("You gave an answer too recently") {
+        Some(Verdict::Wait(parse_wait(page).unwrap_or(WRONG_ANSWER_WAIT)))
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else {
+            Some(Verdict::Incorrect)
+        }
+    } else {
+        None
+    }
+}
+
+/// Parses the `1m 23s` out of "You have 1m 23s left to wait".
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for token in page[start..end].split_whitespace() {
+        let (number, unit) = token.split_at(token.len().checked_sub(1)?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// One answer that was sent to the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub at: u64,
+}
+
+/// Every answer submitted so far, used to avoid asking the server things we
+/// can already work out.
+#[derive(Debug, Default, PartialEq)]
+pub struct History(Vec<Submission>);
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        serde_json::from_str(&json).map(History).map_err(|err| {
+            AocError::InvalidInput(format!(
+                "{} is not a submission history: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let json = serde_json::to_string_pretty(&self.0).expect("submissions always serialise");
+        write_atomic(path, &(json + "\n"))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.0.push(submission);
+    }
+
+    /// The verdict for `answer` if it follows from earlier submissions, in
+    /// which case it should not be sent. `now` is in Unix seconds.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<Verdict> {
+        let earlier: Vec<&Submission> = self
+            .0
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+
+        // only a correct answer settles a part; a wrong level page can mean
+        // the part is still locked, which solving the one before it changes
+        if let Some(solved) = earlier.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Some(if solved.answer == answer {
+                Verdict::Correct
+            } else {
+                Verdict::AlreadySolved
+            });
+        }
+        if let Some(repeat) = earlier
+            .iter()
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Some(repeat.verdict);
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                earlier
+                    .iter()
+                    .filter(move |s| s.verdict == verdict)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+            if bound(Verdict::TooHigh).any(|high| value >= high) {
+                return Some(Verdict::TooHigh);
+            }
+            if bound(Verdict::TooLow).any(|low| value <= low) {
+                return Some(Verdict::TooLow);
+            }
+        }
+
+        let ready_at = earlier
+            .iter()
+            .filter_map(|s| match s.verdict {
+                Verdict::Wait(wait) => Some(s.at + wait.as_secs()),
+                v if v.is_wrong() => Some(s.at + WRONG_ANSWER_WAIT.as_secs()),
+                _ => None,
+            })
+            .max()?;
+        (now < ready_at).then(|| Verdict::Wait(Duration::from_secs(ready_at - now)))
+    }
+}
+
+/// The verdict on a submission, and whether it came from the local history
+/// rather than the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub local: bool,
+}
+
+/// Posts answers to the puzzle server, keeping a history of what was sent.
+pub struct Submitter<H: Http> {
+    base_url: String,
+    session: Option<String>,
+    history: PathBuf,
+    throttle: Throttle,
+    http: H,
+}
+
+impl Submitter<Ureq> {
+    /// Reads the session token and server from `AOC_SESSION` and
+    /// `AOC_BASE_URL`.
+    pub fn from_env(root: &Path) -> Self {
+        Submitter::new(
+            root,
+            &http::base_url_from_env(),
+            http::session_from_env(),
+            Ureq::default(),
+        )
+    }
+}
+
+impl<H: Http> Submitter<H> {
+    pub fn new(root: &Path, base_url: &str, session: Option<String>, http: H) -> Self {
+        Submitter {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            history: root.join(HISTORY_FILE),
+            throttle: Throttle::new(root),
+            http,
+        }
+    }
+
+    /// Submits `answer` unless the history already settles it.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Outcome, AocError> {
+        let mut history = History::load(&self.history)?;
+        let now = unix_now();
+        if let Some(verdict) = history.check(day, part, answer, now) {
+            return Ok(Outcome {
+                verdict,
+                local: true,
+            });
+        }
+
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| http::no_session("submit answers"))?;
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        self.throttle.wait_for_turn()?;
+        let page = self.http.post_form(
+            &url,
+            session,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let verdict = parse_response(&page).ok_or_else(|| AocError::Fetch {
+            url,
+            message: "could not find a verdict in the response".to_string(),
+        })?;
+
+        history.record(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            at: now,
+        });
+        history.save(&self.history)?;
+        Ok(Outcome {
+            verdict,
+            local: false,
+        })
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use super::*;
+    use crate::http::mock::MockServer;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            message
+        )
+    }
+
+    fn submission(answer: &str, verdict: Verdict, at: u64) -> Submission {
+        Submission {
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            at,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                Verdict::Incorrect,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 23s left to wait.",
+                Verdict::Wait(Duration::from_secs(83)),
+            ),
+            (
+                "You gave an answer too recently. You have 9s left to wait.",
+                Verdict::Wait(Duration::from_secs(9)),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(Some(verdict), parse_response(&page(message)), "{}", message);
+        }
+        assert_eq!(None, parse_response("<html>Log in</html>"));
+    }
+
+    #[test]
+    fn test_check_repeats_and_bounds() {
+        let history = History(vec![
+            submission("100", Verdict::TooHigh, 0),
+            submission("10", Verdict::TooLow, 100),
+            submission("abc", Verdict::Incorrect, 200),
+        ]);
+        let later = 1000;
+        assert_eq!(Some(Verdict::TooHigh), history.check(1, 1, "100", later));
+        assert_eq!(Some(Verdict::TooHigh), history.check(1, 1, "150", later));
+        assert_eq!(Some(Verdict::TooLow), history.check(1, 1, "-3", later));
+        assert_eq!(Some(Verdict::Incorrect), history.check(1, 1, "abc", later));
+        assert_eq!(None, history.check(1, 1, "50", later));
+        // other parts are unaffected
+        assert_eq!(None, history.check(1, 2, "150", later));
+    }
+
+    #[test]
+    fn test_check_waits_and_solved() {
+        let history = History(vec![submission("5", Verdict::TooLow, 1000)]);
+        assert_eq!(
+            Some(Verdict::Wait(Duration::from_secs(45))),
+            history.check(1, 1, "7", 1015)
+        );
+        assert_eq!(None, history.check(1, 1, "7", 1060));
+
+        let history = History(vec![
+            submission("5", Verdict::TooLow, 0),
+            submission("7", Verdict::Correct, 100),
+        ]);
+        assert_eq!(Some(Verdict::Correct), history.check(1, 1, "7", 1000));
+        assert_eq!(Some(Verdict::AlreadySolved), history.check(1, 1, "8", 1000));
+
+        // neither a wrong level page nor an older already solved verdict
+        // settles anything
+        let history = History(vec![
+            submission("5", Verdict::WrongLevel, 0),
+            submission("6", Verdict::AlreadySolved, 10),
+        ]);
+        assert_eq!(None, history.check(1, 1, "5", 1000));
+        assert_eq!(None, history.check(1, 1, "7", 1000));
+    }
+
+    #[test]
+    fn test_submit_after_wrong_level() {
+        // part 2 stays locked until part 1 has been solved
+        let solved = Arc::new(AtomicBool::new(false));
+        let server = MockServer::start({
+            let solved = Arc::clone(&solved);
+            move |req| {
+                let message = if req.body.contains("level=2") && !solved.load(Ordering::SeqCst) {
+                    "You don't seem to be solving the right level. Did you already complete it?"
+                } else {
+                    "That's the right answer!"
+                };
+                (200, page(message))
+            }
+        });
+        let root = env::temp_dir().join(format!("aoc-submit-level-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let mut submitter = Submitter::new(
+            &root,
+            &server.base_url,
+            Some("secret".to_string()),
+            Ureq::default(),
+        );
+        submitter.throttle.interval = Duration::ZERO;
+
+        let outcome = submitter.submit(6, 2, "17").unwrap();
+        assert_eq!(Verdict::WrongLevel, outcome.verdict);
+        assert!(!outcome.local);
+
+        solved.store(true, Ordering::SeqCst);
+        let outcome = submitter.submit(6, 2, "17").unwrap();
+        assert_eq!(Verdict::Correct, outcome.verdict);
+        assert!(!outcome.local);
+        assert_eq!(2, server.hits());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_submit_against_mock() {
+        // 42 is the answer; the server also insists on the session cookie
+        let server = MockServer::start(|req| {
+            let message = if req.cookie.as_deref() != Some("session=secret") {
+                "Log in"
+            } else if req.body.contains("answer=42") {
+                "That's the right answer!"
+            } else if req.body.contains("answer=99") {
+                "That's not the right answer; your answer is too high."
+            } else {
+                "You gave an answer too recently. You have 30s left to wait."
+            };
+            (200, page(message))
+        });
+        let root = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let mut submitter = Submitter::new(
+            &root,
+            &server.base_url,
+            Some("secret".to_string()),
+            Ureq::default(),
+        );
+        submitter.throttle.interval = Duration::ZERO;
+
+        let outcome = submitter.submit(3, 2, "99").unwrap();
+        assert_eq!(Verdict::TooHigh, outcome.verdict);
+        assert!(!outcome.local);
+        let requests = server.requests();
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2024/day/3/answer", requests[0].url);
+        assert_eq!("level=2&answer=99", requests[0].body);
+
+        // settled locally: the same answer, then one above the bound
+        assert_eq!(
+            Verdict::TooHigh,
+            submitter.submit(3, 2, "99").unwrap().verdict
+        );
+        assert!(submitter.submit(3, 2, "120").unwrap().local);
+        // a plausible answer still has to wait out the cooldown
+        let outcome = submitter.submit(3, 2, "42").unwrap();
+        assert!(matches!(outcome.verdict, Verdict::Wait(_)) && outcome.local);
+        assert_eq!(1, server.hits());
+
+        // forget the cooldown by rewriting the history as if it were older
+        let mut history = History::load(&root.join(HISTORY_FILE)).unwrap();
+        history.0[0].at -= 120;
+        history.save(&root.join(HISTORY_FILE)).unwrap();
+        let outcome = submitter.submit(3, 2, "42").unwrap();
+        assert_eq!(Verdict::Correct, outcome.verdict);
+        assert!(!outcome.local);
+        assert_eq!(2, server.hits());
+        assert!(submitter.submit(3, 2, "42").unwrap().local);
+
+        let unknown = submitter.submit(4, 1, "1");
+        assert!(matches!(
+            unknown,
+            Ok(Outcome {
+                verdict: Verdict::Wait(_),
+                local: false
+            })
+        ));
+        assert_eq!(3, History::load(&root.join(HISTORY_FILE)).unwrap().0.len());
+
+        submitter.session = Some("stale".to_string());
+        assert!(matches!(
+            submitter.submit(5, 1, "1"),
+            Err(AocError::Fetch { .. })
+        ));
+        assert_eq!(3, History::load(&root.join(HISTORY_FILE)).unwrap().0.len());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
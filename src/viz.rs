@@ -0,0 +1,241 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{viz::Frame, AocError, Solution};
+use termion::{
+    clear, cursor, event::Key, input::TermRead, raw::IntoRawMode, screen::IntoAlternateScreen,
+};
+
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+/// Frames ranked by how likely they are to hold the answer, best first.
+pub type Ranked = fn(&str, usize) -> Result<Frames, AocError>;
+
+/// A day/part whose simulation can be played back frame by frame.
+pub struct Sim {
+    pub day: u8,
+    pub part: u8,
+    /// Parses the puzzle input and starts the simulation.
+    pub frames: fn(&str) -> Result<Frames, AocError>,
+    /// The given number of frames most likely to hold the answer, best
+    /// first, for simulations that can rank them.
+    pub candidates: Option<Ranked>,
+}
+
+/// Every simulation `aoc viz` can show, in day/part order.
+pub const SIMS: &[Sim] = &[
+    Sim {
+        day: 6,
+        part: 1,
+        frames: |input| {
+            let grid = day_06::part1::Part1::parse(input)?;
+            Ok(Box::new(day_06::part1::frames(grid)))
+        },
+        candidates: None,
+    },
+    Sim {
+        day: 14,
+        part: 2,
+        frames: |input| {
+            let robots = day_14::part2::Part2::parse(input)?;
+            Ok(Box::new(day_14::part2::frames(robots, 101, 103)))
+        },
+        candidates: Some(|input, count| {
+            let robots = day_14::part2::Part2::parse(input)?;
+            let times = day_14::part2::candidates(&robots, 101, 103, count);
+            Ok(Box::new(day_14::part2::frames_at(robots, 101, 103, times)))
+        }),
+    },
+    Sim {
+        day: 15,
+        part: 1,
+        frames: |input| {
+            let (grid, moves) = day_15::part1::Part1::parse(input)?;
+            Ok(Box::new(day_15::part1::frames(grid, moves)))
+        },
+        candidates: None,
+    },
+    Sim {
+        day: 15,
+        part: 2,
+        frames: |input| {
+            let (grid, moves) = day_15::part2::Part2::parse(input)?;
+            Ok(Box::new(day_15::part2::frames(grid, moves)))
+        },
+        candidates: None,
+    },
+];
+
+/// The simulation for `day`, taking the first one of the day when `part`
+/// is not given.
+pub fn find(day: u8, part: Option<u8>) -> Option<&'static Sim> {
+    SIMS.iter()
+        .find(|sim| sim.day == day && part.is_none_or(|part| sim.part == part))
+}
+
+const MAX_FPS: u32 = 1000;
+/// How often keys are checked while waiting for the next frame.
+const POLL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    None,
+    Redraw,
+    Step,
+    Quit,
+}
+
+/// Plays frames in the terminal. Space pauses, `n` or the right arrow
+/// steps one frame, `+` and `-` change the speed and `q` quits.
+pub struct Player {
+    fps: u32,
+    paused: bool,
+}
+
+impl Player {
+    pub fn new(fps: u32) -> Self {
+        Player {
+            fps: fps.clamp(1, MAX_FPS),
+            paused: false,
+        }
+    }
+
+    fn handle(&mut self, key: Key) -> Action {
+        match key {
+            Key::Char('q') | Key::Esc | Key::Ctrl('c') => Action::Quit,
+            Key::Char(' ') => {
+                self.paused = !self.paused;
+                Action::Redraw
+            }
+            Key::Char('n') | Key::Right => {
+                self.paused = true;
+                Action::Step
+            }
+            Key::Char('+') | Key::Char('=') => {
+                self.fps = (self.fps * 2).min(MAX_FPS);
+                Action::Redraw
+            }
+            Key::Char('-') => {
+                self.fps = (self.fps / 2).max(1);
+                Action::Redraw
+            }
+            _ => Action::None,
+        }
+    }
+
+    fn status(&self, index: usize, done: bool) -> String {
+        let state = match (done, self.paused) {
+            (true, _) => "done",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        format!(
+            "frame {} | {} fps | {} | space pause, n step, +/- speed, q quit",
+            index, self.fps, state
+        )
+    }
+
+    pub fn play(&mut self, frames: Frames) -> Result<(), AocError> {
+        let raw = io::stdout()
+            .into_raw_mode()
+            .map_err(|_| AocError::Usage("viz needs a terminal, try --dump DIR".to_string()))?;
+        let mut screen = raw.into_alternate_screen().map_err(terminal_error)?;
+        write!(screen, "{}", cursor::Hide).map_err(terminal_error)?;
+        let result = self.play_on(&mut screen, frames);
+        // the screen is restored on drop, but the cursor is not
+        write!(screen, "{}", cursor::Show).map_err(terminal_error)?;
+        result
+    }
+
+    fn play_on(&mut self, screen: &mut impl Write, frames: Frames) -> Result<(), AocError> {
+        let mut keys = termion::async_stdin().keys();
+        let mut frames = frames.enumerate().peekable();
+        while let Some((index, frame)) = frames.next() {
+            let done = frames.peek().is_none();
+            draw(screen, &frame, &self.status(index, done))?;
+            let deadline = Instant::now() + Duration::from_secs(1) / self.fps;
+            loop {
+                let mut step = false;
+                for key in keys.by_ref().map_while(Result::ok) {
+                    match self.handle(key) {
+                        Action::Quit => return Ok(()),
+                        Action::Step => step = true,
+                        Action::Redraw => draw(screen, &frame, &self.status(index, done))?,
+                        Action::None => (),
+                    }
+                }
+                let due = !self.paused && Instant::now() >= deadline;
+                // the last frame stays up until the user quits
+                if !done && (step || due) {
+                    break;
+                }
+                thread::sleep(POLL);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn draw(screen: &mut impl Write, frame: &Frame, status: &str) -> Result<(), AocError> {
+    write!(
+        screen,
+        "{}{}{}\r\n\r\n{}",
+        clear::All,
+        cursor::Goto(1, 1),
+        frame.to_ansi(),
+        status
+    )
+    .and_then(|_| screen.flush())
+    .map_err(terminal_error)
+}
+
+fn terminal_error(err: io::Error) -> AocError {
+    AocError::Usage(format!("could not draw to the terminal: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(Some(1), find(6, None).map(|sim| sim.part));
+        assert_eq!(Some(2), find(15, Some(2)).map(|sim| sim.part));
+        assert!(find(6, Some(2)).is_none());
+        assert!(find(1, None).is_none());
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut player = Player::new(10);
+        assert_eq!(Action::Redraw, player.handle(Key::Char(' ')));
+        assert!(player.paused);
+        assert_eq!(Action::Step, player.handle(Key::Right));
+        assert!(player.paused);
+        player.handle(Key::Char('+'));
+        assert_eq!(20, player.fps);
+        for _ in 0..10 {
+            player.handle(Key::Char('-'));
+        }
+        assert_eq!(1, player.fps);
+        assert_eq!(Action::None, player.handle(Key::Char('x')));
+        assert_eq!(Action::Quit, player.handle(Key::Char('q')));
+        assert_eq!(
+            "frame 3 | 1 fps | paused | space pause, n step, +/- speed, q quit",
+            player.status(3, false)
+        );
+    }
+
+    #[test]
+    fn test_dump_example() {
+        let input = std::fs::read_to_string("day-15/data/examples/small.txt").unwrap();
+        let frames = (find(15, Some(1)).unwrap().frames)(&input).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-viz-dump-{}", std::process::id()));
+        assert_eq!(16, aoc_common::viz::dump(frames, &dir).unwrap());
+        let last = std::fs::read_to_string(dir.join("frame-0015.txt")).unwrap();
+        assert!(last.ends_with("15 of 15 moves, last W\n"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use aoc_common::AocError;
+use notify::{RecursiveMode, Watcher};
+
+/// How long to wait for a burst of saves to settle before re-running.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Pass and fail counts summed over every `test result:` line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl TestSummary {
+    pub fn from_output(output: &str) -> Self {
+        let count = |line: &str, label: &str| -> usize {
+            line.split(';')
+                .find_map(|field| field.trim().strip_suffix(label))
+                .and_then(|n| n.trim().rsplit(' ').next()?.parse().ok())
+                .unwrap_or(0)
+        };
+        output
+            .lines()
+            .filter(|line| line.starts_with("test result:"))
+            .fold(TestSummary::default(), |total, line| TestSummary {
+                passed: total.passed + count(line, "passed"),
+                failed: total.failed + count(line, "failed"),
+            })
+    }
+}
+
+/// One day/part's answer from the last run, kept to diff against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Run {
+    Answer { answer: String, solve_ms: f64 },
+    Failed(String),
+}
+
+/// How the new run compares with the previous one, for the status line.
+pub fn describe_change(previous: Option<&Run>, current: &Run) -> String {
+    match (previous, current) {
+        (_, Run::Failed(err)) => format!("error: {}", err),
+        (Some(Run::Answer { answer: old, .. }), Run::Answer { answer, solve_ms })
+            if old == answer =>
+        {
+            format!("{} (unchanged, {:.2}ms)", answer, solve_ms)
+        }
+        (Some(Run::Answer { answer: old, .. }), Run::Answer { answer, solve_ms }) => {
+            format!("{} (was {}, {:.2}ms)", answer, old, solve_ms)
+        }
+        (_, Run::Answer { answer, solve_ms }) => format!("{} ({:.2}ms)", answer, solve_ms),
+    }
+}
+
+/// Whether `event` is a change worth re-running for. Reads are not, or the
+/// builds we start would keep triggering themselves.
+fn is_change(event: &notify::Result<notify::Event>) -> bool {
+    use notify::EventKind;
+    event.as_ref().is_ok_and(|event| {
+        matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) && event.paths.iter().any(|path| is_relevant(path))
+    })
+}
+
+/// Editor swap files and the like should not trigger a run.
+fn is_relevant(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy())
+        .unwrap_or_default();
+    !(name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp"))
+}
+
+/// Re-runs a day's example tests and solver every time its sources or data
+/// change, until interrupted.
+pub struct Watch {
+    pub day: u8,
+    pub parts: Vec<u8>,
+    pub input: PathBuf,
+    previous: Vec<Option<Run>>,
+}
+
+impl Watch {
+    pub fn new(day: u8, parts: Vec<u8>, input: PathBuf) -> Self {
+        let previous = vec![None; parts.len()];
+        Watch {
+            day,
+            parts,
+            input,
+            previous,
+        }
+    }
+
+    fn package(&self) -> String {
+        format!("day-{:02}", self.day)
+    }
+
+    pub fn run(&mut self) -> Result<(), AocError> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).map_err(watch_error)?;
+        for dir in ["src", "data"] {
+            let path = Path::new(&self.package()).join(dir);
+            watcher
+                .watch(&path, RecursiveMode::Recursive)
+                .map_err(watch_error)?;
+        }
+        println!(
+            "watching {0}/src and {0}/data, ctrl-c to stop",
+            self.package()
+        );
+
+        self.run_once();
+        loop {
+            let event = rx.recv().map_err(|_| watch_stopped())?;
+            let mut relevant = is_change(&event);
+            // swallow the rest of the burst so one save means one run
+            let deadline = Instant::now() + DEBOUNCE;
+            while let Some(wait) = deadline.checked_duration_since(Instant::now()) {
+                match rx.recv_timeout(wait) {
+                    Ok(event) => relevant |= is_change(&event),
+                    Err(mpsc::RecvTimeoutError::Timeout) => break,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return Err(watch_stopped()),
+                }
+            }
+            if relevant {
+                self.run_once();
+                // anything that arrived during the run has been picked up by it
+                while rx.try_recv().is_ok() {}
+            }
+        }
+    }
+
+    /// Runs the example tests and then the real input for each part, and
+    /// prints one line per step.
+    fn run_once(&mut self) {
+        println!("--- {}", self.package());
+        for i in 0..self.parts.len() {
+            let part = self.parts[i];
+            let tests = self.run_tests(part);
+            let status = match tests {
+                Ok(summary) if summary.failed == 0 => {
+                    format!("examples ok ({} passed)", summary.passed)
+                }
+                Ok(summary) => format!(
+                    "examples FAILED ({} of {})",
+                    summary.failed,
+                    summary.passed + summary.failed
+                ),
+                Err(err) => format!("examples did not build: {}", err),
+            };
+            let current = self.run_solver(part);
+            println!(
+                "part {}: {} | {}",
+                part,
+                status,
+                describe_change(self.previous[i].as_ref(), &current)
+            );
+            self.previous[i] = Some(current);
+        }
+    }
+
+    fn run_tests(&self, part: u8) -> Result<TestSummary, String> {
+        // the generated fixture tests are named examples::example_<name>_partN
+        let output = Command::new("cargo")
+            .args(["test", "-q", "-p", &self.package(), "--lib", "--"])
+            .arg(format!("_part{}", part))
+            .output()
+            .map_err(|err| err.to_string())?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let summary = TestSummary::from_output(&stdout);
+        if !output.status.success() && summary == TestSummary::default() {
+            return Err(first_error(&String::from_utf8_lossy(&output.stderr)));
+        }
+        Ok(summary)
+    }
+
+    fn run_solver(&self, part: u8) -> Run {
+        let output = Command::new("cargo")
+            .args(["run", "-q", "--release", "-p", &self.package(), "--bin"])
+            .arg(format!("part{}", part))
+            .args(["--", "--format", "json"])
+            .arg(&self.input)
+            .output();
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => return Run::Failed(err.to_string()),
+        };
+        if !output.status.success() {
+            return Run::Failed(first_error(&String::from_utf8_lossy(&output.stderr)));
+        }
+        let report: serde_json::Value = match serde_json::from_slice(&output.stdout) {
+            Ok(report) => report,
+            Err(err) => return Run::Failed(format!("unreadable report: {}", err)),
+        };
+        let answer = match &report["answer"] {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        Run::Answer {
+            answer,
+            solve_ms: report["solve_ms"].as_f64().unwrap_or_default(),
+        }
+    }
+}
+
+/// The first line that looks like an error, so failures stay on one line.
+fn first_error(stderr: &str) -> String {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("error") || line.contains("panicked"))
+        .or_else(|| stderr.lines().find(|line| !line.trim().is_empty()))
+        .unwrap_or("no output")
+        .trim()
+        .to_string()
+}
+
+fn watch_error(err: notify::Error) -> AocError {
+    AocError::Usage(format!("could not watch for changes: {}", err))
+}
+
+fn watch_stopped() -> AocError {
+    AocError::Usage("the file watcher stopped".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_from_output() {
+        let output = "\
+running 3 tests
+...
+test result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 12 filtered out; finished in 0.00s
+
+running 2 tests
+.F
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+        assert_eq!(
+            TestSummary {
+                passed: 4,
+                failed: 1
+            },
+            TestSummary::from_output(output)
+        );
+        assert_eq!(TestSummary::default(), TestSummary::from_output(""));
+    }
+
+    #[test]
+    fn test_describe_change() {
+        let run = |answer: &str| Run::Answer {
+            answer: answer.to_string(),
+            solve_ms: 1.5,
+        };
+        assert_eq!("12 (1.50ms)", describe_change(None, &run("12")));
+        assert_eq!(
+            "12 (unchanged, 1.50ms)",
+            describe_change(Some(&run("12")), &run("12"))
+        );
+        assert_eq!(
+            "13 (was 12, 1.50ms)",
+            describe_change(Some(&run("12")), &run("13"))
+        );
+        assert_eq!(
+            "error: boom",
+            describe_change(Some(&run("12")), &Run::Failed("boom".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_is_change() {
+        use notify::{
+            event::{AccessKind, ModifyKind},
+            Event, EventKind,
+        };
+        let event = |kind| Ok(Event::new(kind).add_path(PathBuf::from("day-12/src/part2.rs")));
+        assert!(is_change(&event(EventKind::Modify(ModifyKind::Any))));
+        assert!(!is_change(&event(EventKind::Access(AccessKind::Any))));
+    }
+
+    #[test]
+    fn test_is_relevant() {
+        assert!(is_relevant(Path::new("day-12/src/part2.rs")));
+        assert!(is_relevant(Path::new("day-12/data/examples/small.txt")));
+        assert!(!is_relevant(Path::new("day-12/src/.part2.rs.swp")));
+        assert!(!is_relevant(Path::new("day-12/src/part2.rs~")));
+    }
+
+    #[test]
+    fn test_first_error() {
+        let stderr = "   Compiling day-12\nerror[E0308]: mismatched types\n  --> src/part2.rs\n";
+        assert_eq!("error[E0308]: mismatched types", first_error(stderr));
+        assert_eq!("no output", first_error(""));
+    }
+}